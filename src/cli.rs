@@ -0,0 +1,195 @@
+use std::{error::Error, fmt::Display};
+
+use crate::model::clock::TimeControl;
+
+pub const USAGE: &str = "\
+Usage:
+  chessterm                      start the terminal user interface
+  chessterm --pgn <file>         start the interface and save the game as PGN on exit
+  chessterm --clock <control>    play with clocks, e.g. 5+3, 5d2 (delay), 5b2 (Bronstein)
+                                 or 40/90:30+30 (40 moves in 90 minutes, then 30 minutes)
+  chessterm --confirm-takebacks  let the opponent accept or decline each takeback
+  chessterm --pause-on-blur      stop the clocks while the terminal is not focused
+  chessterm perft <fen> <depth>  count leaf nodes, split by root move";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Play {
+        pgn_output: Option<String>,
+        time_control: Option<TimeControl>,
+        confirm_takebacks: bool,
+        pause_on_focus_loss: bool,
+    },
+    Perft {
+        fen: String,
+        depth: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliError(pub String);
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n\n{}", self.0, USAGE)
+    }
+}
+
+impl Error for CliError {}
+
+/// Parses the command line arguments, without the program name.
+pub fn parse_args(args: &[String]) -> Result<Command, CliError> {
+    match args.first().map(String::as_str) {
+        Some("perft") => match args {
+            [_, fen, depth] => {
+                let depth = depth
+                    .parse()
+                    .map_err(|_| CliError(format!("'{}' is not a valid depth", depth)))?;
+                Ok(Command::Perft {
+                    fen: fen.clone(),
+                    depth,
+                })
+            }
+            _ => Err(CliError(String::from(
+                "perft expects a quoted FEN and a depth",
+            ))),
+        },
+        _ => parse_play_args(args),
+    }
+}
+
+fn parse_play_args(args: &[String]) -> Result<Command, CliError> {
+    let mut pgn_output = None;
+    let mut time_control = None;
+    let mut confirm_takebacks = false;
+    let mut pause_on_focus_loss = false;
+    let mut args = args.iter();
+
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            "--pgn" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| CliError(String::from("--pgn expects a file path")))?;
+                pgn_output = Some(path.clone());
+            }
+            "--clock" => {
+                let spec = args
+                    .next()
+                    .ok_or_else(|| CliError(String::from("--clock expects a time control")))?;
+                time_control = Some(
+                    spec.parse()
+                        .map_err(|error| CliError(format!("{}", error)))?,
+                );
+            }
+            "--confirm-takebacks" => confirm_takebacks = true,
+            "--pause-on-blur" => pause_on_focus_loss = true,
+            argument => return Err(CliError(format!("unknown argument '{}'", argument))),
+        }
+    }
+
+    Ok(Command::Play {
+        pgn_output,
+        time_control,
+        confirm_takebacks,
+        pause_on_focus_loss,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_no_arguments() {
+        assert_eq!(
+            parse_args(&args(&[])),
+            Ok(Command::Play {
+                pgn_output: None,
+                time_control: None,
+                confirm_takebacks: false,
+                pause_on_focus_loss: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_pgn_output() {
+        assert_eq!(
+            parse_args(&args(&["--pgn", "game.pgn"])),
+            Ok(Command::Play {
+                pgn_output: Some(String::from("game.pgn")),
+                time_control: None,
+                confirm_takebacks: false,
+                pause_on_focus_loss: false
+            })
+        );
+        assert!(parse_args(&args(&["--pgn"])).is_err());
+        assert!(parse_args(&args(&["--pgn", "a.pgn", "b.pgn"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_clock() {
+        assert_eq!(
+            parse_args(&args(&["--clock", "5+3", "--pgn", "game.pgn"])),
+            Ok(Command::Play {
+                pgn_output: Some(String::from("game.pgn")),
+                time_control: Some("5+3".parse().unwrap()),
+                confirm_takebacks: false,
+                pause_on_focus_loss: false
+            })
+        );
+        assert!(parse_args(&args(&["--clock"])).is_err());
+        assert!(parse_args(&args(&["--clock", "fast"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_confirm_takebacks() {
+        assert_eq!(
+            parse_args(&args(&["--confirm-takebacks"])),
+            Ok(Command::Play {
+                pgn_output: None,
+                time_control: None,
+                confirm_takebacks: true,
+                pause_on_focus_loss: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_pause_on_blur() {
+        assert_eq!(
+            parse_args(&args(&["--clock", "3+2", "--pause-on-blur"])),
+            Ok(Command::Play {
+                pgn_output: None,
+                time_control: Some("3+2".parse().unwrap()),
+                confirm_takebacks: false,
+                pause_on_focus_loss: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_perft() {
+        let command = parse_args(&args(&["perft", "8/8/8/8/8/8/8/K6k w - - 0 1", "3"]));
+
+        assert_eq!(
+            command,
+            Ok(Command::Perft {
+                fen: String::from("8/8/8/8/8/8/8/K6k w - - 0 1"),
+                depth: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_perft() {
+        assert!(parse_args(&args(&["perft", "fen"])).is_err());
+        assert!(parse_args(&args(&["perft", "fen", "deep"])).is_err());
+        assert!(parse_args(&args(&["--unknown"])).is_err());
+    }
+}
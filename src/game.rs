@@ -1,12 +1,34 @@
-use std::error;
+use std::{
+    error, fs, io,
+    time::{Duration, Instant, SystemTime},
+};
 
-use crate::model::{menu::MenuState, Board, Direction};
+use ratatui::layout::Rect;
+
+use crate::model::{
+    clock::ChessClock,
+    command_bar::CommandBarState,
+    drag::DragState,
+    fen::FenError,
+    menu::{MenuState, LOAD_FEN_ITEM, OPEN_PGN_ITEM},
+    move_list::{HistoryView, MoveList},
+    orientation::{board_direction, Orientation},
+    pgn::{parse_pgn, pgn_file_name, PgnTags},
+    promotion::PromotionState,
+    replay::{GameListState, ReplayState},
+    takeback::{TakebackRequest, UndoStack},
+    text_input::TextInput,
+    Board, Direction, PieceColor, Position,
+};
 
 pub type AppResult<T> = std::result::Result<T, Box<dyn error::Error>>;
 
 pub enum CurrentScreen {
     Menu,
     Game,
+    FenInput,
+    PgnInput,
+    GameList,
     Exit,
 }
 
@@ -15,6 +37,41 @@ pub struct Game {
     pub board: Board,
     pub current_screen: CurrentScreen,
     pub menu_state: MenuState,
+    pub promotion_state: Option<PromotionState>,
+    /// Open while a move is being typed on the game screen.
+    pub command_bar: Option<CommandBarState>,
+    pub fen_input: TextInput,
+    pub fen_error: Option<FenError>,
+    pub pgn_input: TextInput,
+    pub pgn_error: Option<String>,
+    pub game_list: GameListState,
+    /// Set while stepping through a loaded game instead of playing one.
+    pub replay: Option<ReplayState>,
+    /// Set while looking at an earlier position of the live game.
+    pub history_view: Option<HistoryView>,
+    /// SAN of the live game's moves, or of the whole game while replaying, written
+    /// down as the moves are made so the move list is not rebuilt every frame.
+    pub move_list: MoveList,
+    pub drag: Option<DragState>,
+    pub orientation: Orientation,
+    /// Present when playing with a time control.
+    pub clock: Option<ChessClock>,
+    /// When the clock was last brought up to date.
+    pub clock_updated: Instant,
+    pub undo_stack: UndoStack,
+    /// Whether taking back a move needs the opponent's consent.
+    pub confirm_takebacks: bool,
+    pub takeback_request: Option<TakebackRequest>,
+    /// Pasted FEN or PGN waiting for confirmation before it replaces a game in progress.
+    pub pending_paste: Option<String>,
+    pub has_focus: bool,
+    /// Whether clocks stop while the terminal window is not focused.
+    pub pause_on_focus_loss: bool,
+    /// Where the board and the menu entries were last drawn, for mouse hit testing.
+    pub board_area: Rect,
+    pub menu_area: Rect,
+    /// Feedback shown above the board, such as where a game was saved.
+    pub status_message: Option<String>,
 }
 
 impl Default for Game {
@@ -24,6 +81,29 @@ impl Default for Game {
             board: Board::default(),
             current_screen: CurrentScreen::Menu,
             menu_state: MenuState::default(),
+            promotion_state: None,
+            command_bar: None,
+            fen_input: TextInput::default(),
+            fen_error: None,
+            pgn_input: TextInput::default(),
+            pgn_error: None,
+            game_list: GameListState::default(),
+            replay: None,
+            history_view: None,
+            move_list: MoveList::default(),
+            drag: None,
+            orientation: Orientation::default(),
+            clock: None,
+            clock_updated: Instant::now(),
+            undo_stack: UndoStack::default(),
+            confirm_takebacks: false,
+            takeback_request: None,
+            pending_paste: None,
+            has_focus: true,
+            pause_on_focus_loss: false,
+            board_area: Rect::default(),
+            menu_area: Rect::default(),
+            status_message: None,
         }
     }
 }
@@ -33,6 +113,15 @@ impl Game {
         self.current_screen = CurrentScreen::Game;
     }
 
+    /// Opens the screen behind the highlighted menu entry.
+    pub fn select_menu_item(&mut self) {
+        match self.menu_state.selected_item() {
+            LOAD_FEN_ITEM => self.open_fen_input(),
+            OPEN_PGN_ITEM => self.open_pgn_input(),
+            _ => self.current_screen = CurrentScreen::Game,
+        }
+    }
+
     pub fn move_menu_cursor(&mut self, direction: Direction) {
         match direction {
             Direction::Up => self.menu_state.previous(),
@@ -41,9 +130,798 @@ impl Game {
         }
     }
 
+    /// Whether the board is currently drawn with Black at the bottom.
+    pub fn is_board_flipped(&self) -> bool {
+        self.orientation.is_flipped(self.board.on_turn)
+    }
+
+    pub fn flip_board(&mut self) {
+        self.orientation = self.orientation.flip(self.board.on_turn);
+        self.drag = None;
+    }
+
+    pub fn toggle_auto_orientation(&mut self) {
+        self.orientation = self.orientation.toggle_auto(self.board.on_turn);
+        self.status_message = Some(String::from(match self.orientation {
+            Orientation::SideToMove => "Board turns to the side to move",
+            _ => "Board orientation fixed",
+        }));
+    }
+
+    /// Moves the cursor in the direction of an arrow key as the board is drawn.
+    pub fn move_board_cursor(&mut self, direction: Direction) {
+        let flipped = self.is_board_flipped();
+        self.board.move_cursor(board_direction(direction, flipped));
+    }
+
+    /// The position on screen: an earlier one while browsing the history, otherwise
+    /// the live game.
+    pub fn displayed_board(&self) -> &Board {
+        self.history_view
+            .as_ref()
+            .map_or(&self.board, |history_view| &history_view.board)
+    }
+
+    /// Moves of the game shown in the move list, and how many of them are on the board.
+    pub fn move_list(&self) -> (&MoveList, usize) {
+        let ply = match (&self.replay, &self.history_view) {
+            (Some(replay), _) => replay.ply,
+            (None, Some(history_view)) => history_view.ply,
+            (None, None) => self.board.move_history().len(),
+        };
+        (&self.move_list, ply)
+    }
+
+    /// Steps back through the live game, leaving it untouched.
+    pub fn history_back(&mut self) {
+        self.drag = None;
+        self.history_view
+            .get_or_insert_with(|| HistoryView::new(&self.board))
+            .back();
+    }
+
+    /// Steps forward, returning to the live game after its last move.
+    pub fn history_forward(&mut self) {
+        if let Some(history_view) = &mut self.history_view {
+            history_view.forward(&self.board);
+            if history_view.is_live(&self.board) {
+                self.history_view = None;
+            }
+        }
+    }
+
+    pub fn history_to_start(&mut self) {
+        self.drag = None;
+        self.history_view
+            .get_or_insert_with(|| HistoryView::new(&self.board))
+            .to_start();
+    }
+
+    pub fn return_to_game(&mut self) {
+        self.history_view = None;
+    }
+
+    /// Plays the move to the cursor, opening the promotion picker first when a pawn
+    /// reaches the last rank.
+    pub fn move_to_cursor(&mut self) {
+        let cursor_position = self.board.cursor_position.clone();
+
+        if self.board.is_promotion_move(&cursor_position) {
+            self.promotion_state = Some(PromotionState::new(cursor_position, self.board.on_turn));
+        } else {
+            let plies = self.board.move_history().len();
+            self.board.move_piece(&cursor_position);
+            self.finish_move(plies);
+        }
+    }
+
+    /// Moves the selected piece to a clicked legal target, otherwise selects the
+    /// clicked square and starts dragging its piece.
+    pub fn press_square(&mut self, position: Position, column: u16, row: u16) {
+        self.board.cursor_position = position.clone();
+
+        if self.is_legal_target(&position) {
+            self.move_to_cursor();
+            return;
+        }
+
+        self.board.select_position(&position);
+        if self.board.selected_position.as_ref() == Some(&position) {
+            if let Some(piece) = self.board.board[position.x][position.y] {
+                self.drag = Some(DragState {
+                    from: position,
+                    piece,
+                    column,
+                    row,
+                });
+            }
+        }
+    }
+
+    pub fn drag_to(&mut self, column: u16, row: u16) {
+        if let Some(drag) = &mut self.drag {
+            drag.column = column;
+            drag.row = row;
+        }
+    }
+
+    /// Ends a drag, moving the piece when it was dropped on one of its legal targets.
+    /// Dropping it back where it came from keeps it selected for click-to-move.
+    pub fn drop_piece(&mut self, position: Option<Position>) {
+        if self.drag.take().is_none() {
+            return;
+        }
+
+        if let Some(position) = position {
+            if self.is_legal_target(&position) {
+                self.board.cursor_position = position;
+                self.move_to_cursor();
+            }
+        }
+    }
+
+    fn is_legal_target(&self, position: &Position) -> bool {
+        self.board
+            .currently_legal_moves
+            .as_ref()
+            .is_some_and(|targets| targets.contains(position))
+    }
+
+    pub fn move_promotion_cursor(&mut self, direction: Direction) {
+        if let Some(promotion_state) = &mut self.promotion_state {
+            match direction {
+                Direction::Left | Direction::Up => promotion_state.previous(),
+                Direction::Right | Direction::Down => promotion_state.next(),
+            }
+        }
+    }
+
+    pub fn confirm_promotion(&mut self) {
+        if let Some(promotion_state) = self.promotion_state.take() {
+            let plies = self.board.move_history().len();
+            self.board.move_piece_with_promotion(
+                &promotion_state.target,
+                Some(promotion_state.selected_piece()),
+            );
+            self.finish_move(plies);
+        }
+    }
+
+    pub fn cancel_promotion(&mut self) {
+        self.promotion_state = None;
+    }
+
+    pub fn open_command_bar(&mut self) {
+        if !self.board.result.is_over() {
+            self.command_bar = Some(CommandBarState::default());
+        }
+    }
+
+    pub fn close_command_bar(&mut self) {
+        self.command_bar = None;
+    }
+
+    /// Plays the typed move, or keeps the bar open with the reason it was rejected.
+    pub fn submit_command(&mut self) {
+        let Some(command_bar) = &mut self.command_bar else {
+            return;
+        };
+
+        match command_bar.chess_move(&self.board) {
+            Ok(chess_move) => {
+                let plies = self.board.move_history().len();
+                if self.board.play_move(&chess_move) {
+                    self.board.selected_position = None;
+                    self.board.currently_legal_moves = None;
+                }
+                self.command_bar = None;
+                self.finish_move(plies);
+            }
+            Err(error) => command_bar.validation = Some(Err(error)),
+        }
+    }
+
+    /// Opens the FEN prompt, prefilled with the current position so it can be edited.
+    pub fn open_fen_input(&mut self) {
+        self.fen_input = TextInput::new(&self.board.to_fen());
+        self.fen_error = None;
+        self.current_screen = CurrentScreen::FenInput;
+    }
+
+    /// Starts a game from the typed FEN, or keeps the prompt open with the error.
+    pub fn load_fen(&mut self) {
+        match Board::from_fen(self.fen_input.value.trim()) {
+            Ok(board) => {
+                self.start_game(board);
+                self.fen_error = None;
+                self.current_screen = CurrentScreen::Game;
+            }
+            Err(error) => self.fen_error = Some(error),
+        }
+    }
+
+    pub fn open_pgn_input(&mut self) {
+        self.pgn_error = None;
+        self.current_screen = CurrentScreen::PgnInput;
+    }
+
+    /// Reads the games of the typed PGN file and lists them.
+    pub fn load_pgn_file(&mut self) {
+        let path = self.pgn_input.value.trim();
+
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                let games = parse_pgn(&text);
+                if games.is_empty() {
+                    self.pgn_error = Some(format!("No games found in {}", path));
+                } else {
+                    self.game_list = GameListState::new(games);
+                    self.pgn_error = None;
+                    self.current_screen = CurrentScreen::GameList;
+                }
+            }
+            Err(error) => self.pgn_error = Some(format!("Could not read {}: {}", path, error)),
+        }
+    }
+
+    /// Shows the selected game from its starting position.
+    pub fn start_replay(&mut self) {
+        let Some(pgn_game) = self.game_list.selected_game() else {
+            return;
+        };
+
+        match ReplayState::new(pgn_game) {
+            Ok((replay, board)) => {
+                self.start_game(board);
+                self.move_list = MoveList::new(&self.board, &replay.moves);
+                self.replay = Some(replay);
+                self.pgn_error = None;
+                self.current_screen = CurrentScreen::Game;
+            }
+            Err(error) => self.pgn_error = Some(format!("Cannot replay this game: {}", error)),
+        }
+    }
+
+    pub fn leave_replay(&mut self) {
+        self.replay = None;
+        self.start_game(Board::default());
+        self.current_screen = CurrentScreen::GameList;
+    }
+
+    /// Layout areas are stale after a resize, so an ongoing drag is dropped.
+    pub fn resize(&mut self) {
+        self.drag = None;
+    }
+
+    pub fn set_focus(&mut self, has_focus: bool) {
+        self.has_focus = has_focus;
+        if !has_focus {
+            self.drag = None;
+        }
+    }
+
+    /// Pastes into the active text input, or otherwise loads a pasted FEN or PGN.
+    pub fn paste(&mut self, text: &str) {
+        match self.current_screen {
+            CurrentScreen::FenInput => {
+                self.fen_input.insert_str(text);
+                return;
+            }
+            CurrentScreen::PgnInput => {
+                self.pgn_input.insert_str(text);
+                return;
+            }
+            CurrentScreen::Game => {
+                if let Some(command_bar) = &mut self.command_bar {
+                    command_bar.input.insert_str(text);
+                    command_bar.update(&self.board);
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        // A game in progress is only replaced once the player agrees
+        let in_progress = matches!(self.current_screen, CurrentScreen::Game)
+            && self.replay.is_none()
+            && !self.board.move_history().is_empty()
+            && !self.board.result.is_over();
+        if in_progress {
+            self.pending_paste = Some(text.to_string());
+            self.status_message = Some(String::from(
+                "Replace the current game with the pasted text? y/n",
+            ));
+            return;
+        }
+
+        self.load_pasted(text);
+    }
+
+    /// The player's answer to replacing the game with pasted text.
+    pub fn answer_paste(&mut self, accept: bool) {
+        let Some(text) = self.pending_paste.take() else {
+            return;
+        };
+
+        if accept {
+            self.load_pasted(&text);
+        } else {
+            self.status_message = None;
+        }
+    }
+
+    /// Starts a game from a pasted FEN or lists the games of a pasted PGN.
+    fn load_pasted(&mut self, text: &str) {
+        if let Ok(board) = Board::from_fen(text.trim()) {
+            self.start_game(board);
+            self.replay = None;
+            self.status_message = Some(String::from("Loaded pasted FEN"));
+            self.current_screen = CurrentScreen::Game;
+            return;
+        }
+
+        let games = parse_pgn(text);
+        if games.iter().any(|pgn_game| !pgn_game.moves.is_empty()) {
+            self.game_list = GameListState::new(games);
+            self.pgn_error = None;
+            self.current_screen = CurrentScreen::GameList;
+        } else {
+            self.status_message = Some(String::from("Pasted text is neither a FEN nor a PGN"));
+        }
+    }
+
+    /// Writes the game so far as PGN to `path`.
+    pub fn write_pgn(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.board.to_pgn(&PgnTags::today()))
+    }
+
+    /// Saves the game to a timestamped PGN file in the working directory.
+    pub fn save_pgn(&mut self) {
+        let path = pgn_file_name(SystemTime::now());
+
+        self.status_message = Some(match self.write_pgn(&path) {
+            Ok(()) => format!("Game saved to {}", path),
+            Err(error) => format!("Could not save game: {}", error),
+        });
+    }
+
     pub fn quit(&mut self) {
         self.is_running = false;
     }
 
-    pub fn tick(&self) {}
+    /// Runs the clock of the side to move, ending the game when its flag falls.
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.clock_updated);
+        self.clock_updated = now;
+        self.advance_clock(elapsed);
+    }
+
+    fn advance_clock(&mut self, elapsed: Duration) {
+        let paused = !matches!(self.current_screen, CurrentScreen::Game)
+            || self.replay.is_some()
+            || self.board.result.is_over()
+            || (self.pause_on_focus_loss && !self.has_focus);
+        if paused {
+            return;
+        }
+
+        if let Some(flagged) = self.clock.as_mut().and_then(|clock| clock.tick(elapsed)) {
+            self.board.lose_on_time(flagged);
+            self.promotion_state = None;
+            self.command_bar = None;
+            self.drag = None;
+        }
+    }
+
+    /// If a move was made since there were `plies`, remembers it for undo and hands
+    /// the clock to the opponent.
+    fn finish_move(&mut self, plies: usize) {
+        if self.board.move_history().len() == plies {
+            return;
+        }
+
+        let mut before = self.board.clone();
+        if let Some(chess_move) = before.unmake_move() {
+            self.move_list.push(&before, &chess_move);
+        }
+
+        // Charge the time up to the move before switching sides
+        self.tick();
+        self.undo_stack.record(self.clock.clone());
+        if let Some(clock) = &mut self.clock {
+            clock.press(self.board.on_turn.opposite());
+        }
+    }
+
+    /// Replaces the live game, keeping the time control for the new one.
+    fn start_game(&mut self, board: Board) {
+        self.move_list = board.move_list();
+        self.board = board;
+        self.history_view = None;
+        self.promotion_state = None;
+        self.undo_stack = UndoStack::default();
+        self.takeback_request = None;
+        self.command_bar = None;
+        self.drag = None;
+        if let Some(clock) = &mut self.clock {
+            *clock = ChessClock::new(clock.time_control.clone());
+        }
+    }
+
+    /// Takes back the last move, or asks the opponent first when takebacks need
+    /// confirmation.
+    pub fn undo_move(&mut self) {
+        if self.confirm_takebacks {
+            self.request_takeback(self.board.on_turn.opposite());
+            return;
+        }
+
+        if self.undo_stack.undo(&mut self.board, &mut self.clock) {
+            self.after_takeback();
+        }
+    }
+
+    pub fn redo_move(&mut self) {
+        if self.confirm_takebacks {
+            self.status_message = Some(String::from(
+                "Redo is off while takebacks need confirmation",
+            ));
+            return;
+        }
+
+        if self.undo_stack.redo(&mut self.board, &mut self.clock) {
+            self.after_takeback();
+        }
+    }
+
+    /// Asks the opponent of `color` to allow taking back its last move.
+    pub fn request_takeback(&mut self, color: PieceColor) {
+        let request = TakebackRequest {
+            requested_by: color,
+        };
+        if request.plies(&self.board) == 0 {
+            return;
+        }
+
+        self.takeback_request = Some(request);
+        self.status_message = Some(format!(
+            "{:?} asks to take back a move: y to accept, n to decline",
+            color
+        ));
+    }
+
+    /// The opponent's answer to a pending takeback request.
+    pub fn answer_takeback(&mut self, accept: bool) {
+        let Some(request) = self.takeback_request.take() else {
+            return;
+        };
+
+        if accept {
+            for _ in 0..request.plies(&self.board) {
+                self.undo_stack.undo(&mut self.board, &mut self.clock);
+            }
+            self.after_takeback();
+        }
+        self.status_message = Some(String::from(if accept {
+            "Takeback accepted"
+        } else {
+            "Takeback declined"
+        }));
+    }
+
+    fn after_takeback(&mut self) {
+        self.move_list = self.board.move_list();
+        self.promotion_state = None;
+        self.command_bar = None;
+        self.drag = None;
+        self.clock_updated = Instant::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_fen_starts_game() {
+        let fen = "4k3/8/8/8/8/8/4P3/4K3 b - - 3 40";
+        let mut game = Game::default();
+        game.open_fen_input();
+        game.fen_input = TextInput::new(fen);
+        game.load_fen();
+
+        assert!(matches!(game.current_screen, CurrentScreen::Game));
+        assert_eq!(game.board.to_fen(), fen);
+    }
+
+    #[test]
+    fn test_click_to_move() {
+        let mut game = Game::default();
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.drop_piece(Some(Position { x: 6, y: 4 }));
+
+        assert_eq!(game.board.selected_position, Some(Position { x: 6, y: 4 }));
+
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+
+        assert!(game.board.board[4][4].is_some());
+        assert_eq!(game.board.on_turn, PieceColor::Black);
+        assert!(game.drag.is_none());
+    }
+
+    #[test]
+    fn test_drag_and_drop() {
+        let mut game = Game::default();
+        game.press_square(Position { x: 7, y: 6 }, 10, 10);
+        game.drag_to(12, 3);
+
+        assert_eq!(
+            game.drag.as_ref().map(|drag| (drag.column, drag.row)),
+            Some((12, 3))
+        );
+
+        game.drop_piece(Some(Position { x: 4, y: 4 }));
+        assert!(game.drag.is_none());
+        assert_eq!(game.board.on_turn, PieceColor::White);
+
+        game.press_square(Position { x: 7, y: 6 }, 10, 10);
+        game.drop_piece(Some(Position { x: 5, y: 5 }));
+        assert!(game.board.board[5][5].is_some());
+        assert_eq!(game.board.on_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_flipped_cursor_movement() {
+        let mut game = Game::default();
+        game.flip_board();
+        game.move_board_cursor(Direction::Up);
+        game.move_board_cursor(Direction::Left);
+
+        assert!(game.is_board_flipped());
+        assert_eq!(game.board.cursor_position, Position { x: 0, y: 1 });
+
+        game.toggle_auto_orientation();
+        assert!(!game.is_board_flipped());
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        assert!(game.is_board_flipped());
+    }
+
+    #[test]
+    fn test_browse_history() {
+        let mut game = Game::default();
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        game.history_back();
+
+        assert_eq!(game.displayed_board(), &Board::default());
+        assert_eq!(game.move_list().1, 0);
+        assert_eq!(game.board.move_history().len(), 1);
+
+        game.history_forward();
+        assert!(game.history_view.is_none());
+        assert_eq!(game.move_list().0.sans, ["e4"]);
+    }
+
+    #[test]
+    fn test_clock_runs_after_first_move() {
+        let mut game = Game {
+            clock: Some(ChessClock::new("1+1".parse().unwrap())),
+            current_screen: CurrentScreen::Game,
+            ..Game::default()
+        };
+        game.advance_clock(Duration::from_secs(30));
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+
+        let clock = game.clock.as_ref().unwrap();
+        assert_eq!(clock.running, Some(PieceColor::Black));
+        assert!(clock.white.remaining > Duration::from_secs(60));
+
+        game.advance_clock(Duration::from_secs(61));
+        assert_eq!(
+            game.board.result,
+            crate::model::GameResult::WhiteWins(crate::model::WinReason::Timeout)
+        );
+    }
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut game = Game::default();
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        game.undo_move();
+
+        assert_eq!(game.board, Board::default());
+        assert_eq!(game.board.on_turn, PieceColor::White);
+
+        game.redo_move();
+        assert_eq!(game.board.on_turn, PieceColor::Black);
+        assert!(game.board.board[4][4].is_some());
+    }
+
+    #[test]
+    fn test_move_list_follows_moves_and_takebacks() {
+        let mut game = Game::default();
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        game.press_square(Position { x: 1, y: 4 }, 0, 0);
+        game.press_square(Position { x: 3, y: 4 }, 0, 0);
+
+        assert_eq!(game.move_list, game.board.move_list());
+        assert_eq!(game.move_list().0.sans, ["e4", "e5"]);
+
+        game.undo_move();
+        assert_eq!(game.move_list().0.sans, ["e4"]);
+
+        game.redo_move();
+        assert_eq!(game.move_list().0.sans, ["e4", "e5"]);
+    }
+
+    #[test]
+    fn test_takeback_needs_confirmation() {
+        let mut game = Game {
+            confirm_takebacks: true,
+            ..Game::default()
+        };
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        game.press_square(Position { x: 1, y: 4 }, 0, 0);
+        game.press_square(Position { x: 3, y: 4 }, 0, 0);
+
+        game.request_takeback(PieceColor::White);
+        assert_eq!(game.board.move_history().len(), 2);
+        game.answer_takeback(false);
+        assert_eq!(game.board.move_history().len(), 2);
+
+        game.request_takeback(PieceColor::White);
+        game.answer_takeback(true);
+        assert_eq!(game.board, Board::default());
+        assert!(game.takeback_request.is_none());
+    }
+
+    #[test]
+    fn test_clock_pauses_on_focus_loss() {
+        let mut game = Game {
+            clock: Some(ChessClock::new("1+0".parse().unwrap())),
+            pause_on_focus_loss: true,
+            current_screen: CurrentScreen::Game,
+            ..Game::default()
+        };
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        let remaining = game.clock.as_ref().unwrap().black.remaining;
+
+        game.set_focus(false);
+        game.advance_clock(Duration::from_secs(10));
+        assert_eq!(game.clock.as_ref().unwrap().black.remaining, remaining);
+
+        game.set_focus(true);
+        game.advance_clock(Duration::from_secs(10));
+        assert_eq!(
+            game.clock.as_ref().unwrap().black.remaining,
+            remaining - Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_paste_fen_or_pgn() {
+        let mut game = Game::default();
+        game.paste("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1\n");
+
+        assert!(matches!(game.current_screen, CurrentScreen::Game));
+        assert_eq!(game.board.to_fen(), "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+
+        game.paste("[Event \"Pasted\"]\n\n1. e4 e5 *\n");
+
+        assert!(matches!(game.current_screen, CurrentScreen::GameList));
+        assert_eq!(game.game_list.games.len(), 1);
+
+        game.paste("hello");
+        assert!(matches!(game.current_screen, CurrentScreen::GameList));
+        assert!(game.status_message.is_some());
+    }
+
+    #[test]
+    fn test_paste_over_game_in_progress() {
+        let mut game = Game::default();
+        game.run();
+        game.press_square(Position { x: 6, y: 4 }, 0, 0);
+        game.press_square(Position { x: 4, y: 4 }, 0, 0);
+        game.paste("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+
+        assert!(game.pending_paste.is_some());
+        assert_eq!(game.board.move_history().len(), 1);
+
+        game.answer_paste(false);
+        assert!(game.pending_paste.is_none());
+        assert_eq!(game.board.move_history().len(), 1);
+
+        game.paste("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+        game.answer_paste(true);
+        assert_eq!(game.board.to_fen(), "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+    }
+
+    #[test]
+    fn test_paste_into_inputs() {
+        let mut game = Game::default();
+        game.open_pgn_input();
+        game.paste("games.pgn");
+
+        assert_eq!(game.pgn_input.value, "games.pgn");
+
+        game.current_screen = CurrentScreen::Game;
+        game.open_command_bar();
+        game.paste("Nf3");
+
+        let command_bar = game.command_bar.as_ref().unwrap();
+        assert_eq!(command_bar.validation, Some(Ok(String::from("Nf3"))));
+    }
+
+    #[test]
+    fn test_typed_move_is_played() {
+        let mut game = Game::default();
+        game.open_command_bar();
+        if let Some(command_bar) = &mut game.command_bar {
+            command_bar.input.insert_str("e9");
+        }
+        game.submit_command();
+
+        assert!(game.command_bar.is_some());
+        assert_eq!(game.board.on_turn, PieceColor::White);
+
+        if let Some(command_bar) = &mut game.command_bar {
+            command_bar.input = TextInput::new("e2e4");
+        }
+        game.submit_command();
+
+        assert!(game.command_bar.is_none());
+        assert_eq!(game.board.on_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_replay_selected_game() {
+        let mut game = Game {
+            game_list: GameListState::new(parse_pgn("1. e4 * 1. e4 e5 2. Ke3 *")),
+            ..Game::default()
+        };
+        game.game_list.next();
+        game.start_replay();
+
+        assert!(game.replay.is_none());
+        assert!(game.pgn_error.is_some());
+
+        game.game_list.previous();
+        game.start_replay();
+
+        assert!(matches!(game.current_screen, CurrentScreen::Game));
+        assert_eq!(game.move_list().0.sans, ["e4"]);
+        let replay = game.replay.as_mut().unwrap();
+        assert!(replay.forward(&mut game.board));
+        assert_eq!(game.board.on_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_missing_pgn_file_is_reported() {
+        let mut game = Game::default();
+        game.open_pgn_input();
+        game.pgn_input = TextInput::new("does/not/exist.pgn");
+        game.load_pgn_file();
+
+        assert!(matches!(game.current_screen, CurrentScreen::PgnInput));
+        assert!(game.pgn_error.unwrap().starts_with("Could not read"));
+    }
+
+    #[test]
+    fn test_invalid_fen_keeps_prompt_open() {
+        let mut game = Game::default();
+        game.open_fen_input();
+        game.fen_input.delete_previous();
+        game.load_fen();
+
+        assert!(matches!(game.current_screen, CurrentScreen::FenInput));
+        assert_eq!(game.fen_error, Some(FenError::WrongFieldCount(5)));
+        assert_eq!(game.board, Board::default());
+    }
 }
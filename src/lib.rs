@@ -1,3 +1,4 @@
+pub mod cli;
 pub mod game;
 pub mod model;
 pub mod update;
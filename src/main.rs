@@ -1,14 +1,93 @@
 use chessterm::{
+    cli::{self, Command},
     game::{AppResult, Game},
+    model::{
+        bitboard::BitboardPosition,
+        clock::{ChessClock, TimeControl},
+        perft::{divide, perft},
+        Board,
+    },
     update::message::{Message, MessageHandler},
     view::tui::Tui,
 };
 
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
+use std::{env, io, process, time::Instant};
 
 fn main() -> AppResult<()> {
-    let mut game = Game::default();
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match cli::parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Play {
+            pgn_output,
+            time_control,
+            confirm_takebacks,
+            pause_on_focus_loss,
+        } => run_tui(
+            pgn_output.as_deref(),
+            time_control,
+            confirm_takebacks,
+            pause_on_focus_loss,
+        ),
+        Command::Perft { fen, depth } => run_perft(&fen, depth),
+    }
+}
+
+fn run_perft(fen: &str, depth: u32) -> AppResult<()> {
+    let board = match Board::from_fen(fen) {
+        Ok(board) => board,
+        Err(error) => {
+            eprintln!("Invalid FEN: {}", error);
+            process::exit(2);
+        }
+    };
+    let position = BitboardPosition::from(&board);
+    let start = Instant::now();
+
+    let nodes = if depth == 0 {
+        perft(&position, depth)
+    } else {
+        divide(&position, depth)
+            .iter()
+            .map(|(chess_move, move_nodes)| {
+                println!("{}: {}", chess_move, move_nodes);
+                move_nodes
+            })
+            .sum()
+    };
+
+    let elapsed = start.elapsed();
+    println!();
+    println!("Nodes searched: {}", nodes);
+    println!(
+        "Time: {} ms ({:.0} nodes/s)",
+        elapsed.as_millis(),
+        nodes as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+    );
+
+    Ok(())
+}
+
+fn run_tui(
+    pgn_output: Option<&str>,
+    time_control: Option<TimeControl>,
+    confirm_takebacks: bool,
+    pause_on_focus_loss: bool,
+) -> AppResult<()> {
+    let mut game = Game {
+        clock: time_control.map(ChessClock::new),
+        confirm_takebacks,
+        pause_on_focus_loss,
+        ..Game::default()
+    };
     let backend = CrosstermBackend::new(io::stderr());
     let terminal = Terminal::new(backend)?;
     let message_handler = MessageHandler::new(250);
@@ -24,11 +103,31 @@ fn main() -> AppResult<()> {
             Message::KeyPress(key_event) => tui
                 .message_handler
                 .handle_key_events(key_event, &mut game)?,
-            _ => {}
+            Message::MousePress(mouse_event) => tui
+                .message_handler
+                .handle_mouse_events(mouse_event, &mut game)?,
+            Message::Resize(width, height) => {
+                tui.resize(width, height)?;
+                game.resize();
+            }
+            Message::FocusGained => game.set_focus(true),
+            Message::FocusLost => game.set_focus(false),
+            Message::Paste(text) => game.paste(&text),
+            Message::Quit => game.quit(),
         }
     }
 
     tui.exit()?;
 
+    if let Some(path) = pgn_output {
+        match game.write_pgn(path) {
+            Ok(()) => println!("Game saved to {}", path),
+            Err(error) => {
+                eprintln!("Could not save game to {}: {}", path, error);
+                process::exit(1);
+            }
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,170 @@
+use super::{
+    bitboard::{
+        attacks::piece_attacks,
+        position::{position_of, square_of},
+        Bitboard,
+    },
+    Board, Piece, PieceColor, Position,
+};
+
+/// Every square attacked by one side, at least once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AttackMap(pub Bitboard);
+
+impl AttackMap {
+    pub fn is_attacked(&self, position: &Position) -> bool {
+        self.0 & 1 << square_of(position) != 0
+    }
+
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn squares(&self) -> Vec<Position> {
+        (0..64)
+            .filter(|square| self.0 & 1 << square != 0)
+            .map(position_of)
+            .collect()
+    }
+}
+
+impl Board {
+    fn occupancy(&self) -> Bitboard {
+        self.board
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, cell)| cell.is_some())
+            .fold(0, |occupied, (square, _)| occupied | 1 << square)
+    }
+
+    /// Pieces of `color` with the squares they stand on.
+    fn pieces_of(&self, color: PieceColor) -> impl Iterator<Item = (usize, Piece)> + '_ {
+        self.board
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter_map(move |(square, cell)| match cell {
+                Some(piece) if piece.piece_color == color => Some((square, *piece)),
+                _ => None,
+            })
+    }
+
+    /// Whether any piece of `attacker_color` attacks `position`, whatever stands on it.
+    pub fn is_square_attacked(&self, position: &Position, attacker_color: PieceColor) -> bool {
+        let target = 1 << square_of(position);
+        let occupied = self.occupancy();
+
+        self.pieces_of(attacker_color)
+            .any(|(square, piece)| piece_attacks(&piece, square, occupied) & target != 0)
+    }
+
+    /// Positions of the pieces of `attacker_color` that attack `position`.
+    pub fn attackers_of(&self, position: &Position, attacker_color: PieceColor) -> Vec<Position> {
+        let target = 1 << square_of(position);
+        let occupied = self.occupancy();
+
+        self.pieces_of(attacker_color)
+            .filter(|(square, piece)| piece_attacks(piece, *square, occupied) & target != 0)
+            .map(|(square, _)| position_of(square))
+            .collect()
+    }
+
+    /// All squares attacked by the pieces of `color`.
+    pub fn attack_map(&self, color: PieceColor) -> AttackMap {
+        let occupied = self.occupancy();
+
+        AttackMap(self.pieces_of(color).fold(0, |attacks, (square, piece)| {
+            attacks | piece_attacks(&piece, square, occupied)
+        }))
+    }
+
+    /// Pieces of `color` that are attacked by the opponent and not defended.
+    pub fn hanging_pieces(&self, color: PieceColor) -> Vec<Position> {
+        let attacked = self.attack_map(color.opposite());
+        let defended = self.attack_map(color);
+
+        self.pieces_of(color)
+            .map(|(square, _)| position_of(square))
+            .filter(|position| attacked.is_attacked(position) && !defended.is_attacked(position))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::PieceType;
+
+    fn place(board: &mut Board, square: &str, piece_type: PieceType, color: PieceColor) {
+        let position = Position::from_algebraic(square).unwrap();
+        board.board[position.x][position.y] = Some(Piece::new(piece_type, color));
+    }
+
+    fn at(square: &str) -> Position {
+        Position::from_algebraic(square).unwrap()
+    }
+
+    #[test]
+    fn test_initial_attack_maps() {
+        let board = Board::default();
+
+        // Every square of the third rank plus the defended pieces behind it
+        let white_attacks = board.attack_map(PieceColor::White);
+        assert!(white_attacks.is_attacked(&at("e3")));
+        assert!(white_attacks.is_attacked(&at("f2")));
+        assert!(!white_attacks.is_attacked(&at("e4")));
+        assert!(!white_attacks.is_attacked(&at("a1")));
+        assert_eq!(white_attacks.count(), 22);
+
+        let black_attacks = board.attack_map(PieceColor::Black);
+        assert!(black_attacks.is_attacked(&at("h6")));
+        assert!(!black_attacks.is_attacked(&at("e5")));
+        assert_eq!(black_attacks.count(), 22);
+    }
+
+    #[test]
+    fn test_attackers_of_square() {
+        let mut board = Board::init_empty();
+        place(&mut board, "e4", PieceType::Pawn, PieceColor::Black);
+        place(&mut board, "e1", PieceType::Rook, PieceColor::White);
+        place(&mut board, "d3", PieceType::Pawn, PieceColor::White);
+        place(&mut board, "f3", PieceType::Pawn, PieceColor::White);
+        place(&mut board, "f2", PieceType::Knight, PieceColor::White);
+        place(&mut board, "b7", PieceType::Bishop, PieceColor::White);
+        place(&mut board, "c6", PieceType::Knight, PieceColor::Black);
+
+        let mut attackers = board.attackers_of(&at("e4"), PieceColor::White);
+        attackers.sort_by_key(|position| (position.x, position.y));
+
+        // The bishop is blocked by the knight on c6
+        assert_eq!(attackers, vec![at("d3"), at("f3"), at("f2"), at("e1")]);
+        assert!(board.attackers_of(&at("e4"), PieceColor::Black).is_empty());
+    }
+
+    #[test]
+    fn test_pawns_and_kings_only_attack_what_they_could_capture() {
+        let mut board = Board::init_empty();
+        place(&mut board, "e2", PieceType::Pawn, PieceColor::White);
+        place(&mut board, "e1", PieceType::King, PieceColor::White);
+
+        assert!(!board.is_square_attacked(&at("e3"), PieceColor::White));
+        assert!(!board.is_square_attacked(&at("e4"), PieceColor::White));
+        assert!(board.is_square_attacked(&at("d3"), PieceColor::White));
+        assert!(!board.is_square_attacked(&at("g1"), PieceColor::White));
+        assert!(board.is_square_attacked(&at("f1"), PieceColor::White));
+    }
+
+    #[test]
+    fn test_hanging_pieces() {
+        let mut board = Board::init_empty();
+        place(&mut board, "d4", PieceType::Knight, PieceColor::White);
+        place(&mut board, "h4", PieceType::Bishop, PieceColor::White);
+        place(&mut board, "g3", PieceType::Pawn, PieceColor::White);
+        place(&mut board, "d8", PieceType::Rook, PieceColor::Black);
+        place(&mut board, "h8", PieceType::Rook, PieceColor::Black);
+
+        assert_eq!(board.hanging_pieces(PieceColor::White), vec![at("d4")]);
+        assert!(board.hanging_pieces(PieceColor::Black).is_empty());
+    }
+}
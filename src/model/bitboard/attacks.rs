@@ -0,0 +1,156 @@
+use std::sync::OnceLock;
+
+use crate::model::{Piece, PieceColor, PieceType};
+
+use super::Bitboard;
+
+/// Ray directions as (row, column) steps. Rows grow towards rank 1, columns towards the h-file.
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, 1),
+    (0, -1),
+    (-1, 1),
+    (-1, -1),
+    (1, 1),
+    (1, -1),
+];
+const ROOK_DIRECTIONS: [usize; 4] = [0, 1, 2, 3];
+const BISHOP_DIRECTIONS: [usize; 4] = [4, 5, 6, 7];
+
+const KNIGHT_OFFSETS: [(isize, isize); 8] = [
+    (2, 1),
+    (2, -1),
+    (-2, 1),
+    (-2, -1),
+    (1, 2),
+    (1, -2),
+    (-1, 2),
+    (-1, -2),
+];
+const KING_OFFSETS: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (1, -1),
+    (0, 1),
+    (0, -1),
+    (-1, 0),
+    (-1, 1),
+    (-1, -1),
+];
+
+struct AttackTables {
+    knight: [Bitboard; 64],
+    king: [Bitboard; 64],
+    /// Indexed by color index, white pawns attack towards row 0.
+    pawn: [[Bitboard; 64]; 2],
+    rays: [[Bitboard; 64]; 8],
+}
+
+impl AttackTables {
+    fn new() -> Self {
+        let mut tables = Self {
+            knight: [0; 64],
+            king: [0; 64],
+            pawn: [[0; 64]; 2],
+            rays: [[0; 64]; 8],
+        };
+
+        for square in 0..64 {
+            let (x, y) = ((square / 8) as isize, (square % 8) as isize);
+
+            tables.knight[square] = offsets_to_bitboard(x, y, &KNIGHT_OFFSETS);
+            tables.king[square] = offsets_to_bitboard(x, y, &KING_OFFSETS);
+            tables.pawn[0][square] = offsets_to_bitboard(x, y, &[(-1, -1), (-1, 1)]);
+            tables.pawn[1][square] = offsets_to_bitboard(x, y, &[(1, -1), (1, 1)]);
+
+            for (direction, (dx, dy)) in DIRECTIONS.iter().enumerate() {
+                let (mut ray_x, mut ray_y) = (x + dx, y + dy);
+                while (0..8).contains(&ray_x) && (0..8).contains(&ray_y) {
+                    tables.rays[direction][square] |= 1 << (ray_x * 8 + ray_y);
+                    ray_x += dx;
+                    ray_y += dy;
+                }
+            }
+        }
+
+        tables
+    }
+}
+
+fn offsets_to_bitboard(x: isize, y: isize, offsets: &[(isize, isize)]) -> Bitboard {
+    offsets
+        .iter()
+        .map(|(dx, dy)| (x + dx, y + dy))
+        .filter(|(x, y)| (0..8).contains(x) && (0..8).contains(y))
+        .fold(0, |bitboard, (x, y)| bitboard | 1 << (x * 8 + y))
+}
+
+fn tables() -> &'static AttackTables {
+    static TABLES: OnceLock<AttackTables> = OnceLock::new();
+    TABLES.get_or_init(AttackTables::new)
+}
+
+pub fn knight_attacks(square: usize) -> Bitboard {
+    tables().knight[square]
+}
+
+pub fn king_attacks(square: usize) -> Bitboard {
+    tables().king[square]
+}
+
+pub fn pawn_attacks(color_index: usize, square: usize) -> Bitboard {
+    tables().pawn[color_index][square]
+}
+
+/// Classical ray attacks: each ray is cut off behind its first blocker.
+fn ray_attacks(direction: usize, square: usize, occupied: Bitboard) -> Bitboard {
+    let rays = &tables().rays[direction];
+    let ray = rays[square];
+    let blockers = ray & occupied;
+
+    if blockers == 0 {
+        return ray;
+    }
+
+    let (dx, dy) = DIRECTIONS[direction];
+    let nearest_blocker = if dx * 8 + dy > 0 {
+        blockers.trailing_zeros() as usize
+    } else {
+        63 - blockers.leading_zeros() as usize
+    };
+
+    ray ^ rays[nearest_blocker]
+}
+
+pub fn rook_attacks(square: usize, occupied: Bitboard) -> Bitboard {
+    ROOK_DIRECTIONS.iter().fold(0, |attacks, &direction| {
+        attacks | ray_attacks(direction, square, occupied)
+    })
+}
+
+pub fn bishop_attacks(square: usize, occupied: Bitboard) -> Bitboard {
+    BISHOP_DIRECTIONS.iter().fold(0, |attacks, &direction| {
+        attacks | ray_attacks(direction, square, occupied)
+    })
+}
+
+pub fn queen_attacks(square: usize, occupied: Bitboard) -> Bitboard {
+    rook_attacks(square, occupied) | bishop_attacks(square, occupied)
+}
+
+/// Squares attacked by `piece` standing on `square`. Pawns only attack diagonally
+/// and kings only their neighbours, since pushes and castling never capture.
+pub fn piece_attacks(piece: &Piece, square: usize, occupied: Bitboard) -> Bitboard {
+    match piece.piece_type {
+        PieceType::Pawn => match piece.piece_color {
+            PieceColor::White => pawn_attacks(0, square),
+            PieceColor::Black => pawn_attacks(1, square),
+        },
+        PieceType::Knight => knight_attacks(square),
+        PieceType::Bishop => bishop_attacks(square, occupied),
+        PieceType::Rook => rook_attacks(square, occupied),
+        PieceType::Queen => queen_attacks(square, occupied),
+        PieceType::King => king_attacks(square),
+    }
+}
@@ -0,0 +1,7 @@
+pub mod attacks;
+pub mod position;
+
+pub use position::*;
+
+/// One bit per square, numbered like `position::square_of`.
+pub type Bitboard = u64;
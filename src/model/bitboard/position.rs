@@ -0,0 +1,486 @@
+use crate::model::{
+    home_row, promotion::PROMOTION_PIECES, zobrist, Board, CastlingRights, CastlingSide, ChessMove,
+    ChessPosition, MoveKind, Piece, PieceColor, PieceType, Position,
+};
+
+use super::{
+    attacks::{
+        bishop_attacks, king_attacks, knight_attacks, pawn_attacks, queen_attacks, rook_attacks,
+    },
+    Bitboard,
+};
+
+const PIECE_TYPES: [PieceType; 6] = [
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+    PieceType::King,
+];
+
+fn piece_index(piece_type: PieceType) -> usize {
+    match piece_type {
+        PieceType::Pawn => 0,
+        PieceType::Knight => 1,
+        PieceType::Bishop => 2,
+        PieceType::Rook => 3,
+        PieceType::Queen => 4,
+        PieceType::King => 5,
+    }
+}
+
+fn color_index(color: PieceColor) -> usize {
+    match color {
+        PieceColor::White => 0,
+        PieceColor::Black => 1,
+    }
+}
+
+/// Squares are numbered row by row from a8 (0) to h1 (63), matching `Position`.
+pub fn square_of(position: &Position) -> usize {
+    position.x * 8 + position.y
+}
+
+pub fn position_of(square: usize) -> Position {
+    Position {
+        x: square / 8,
+        y: square % 8,
+    }
+}
+
+fn squares(mut bitboard: Bitboard) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if bitboard == 0 {
+            return None;
+        }
+        let square = bitboard.trailing_zeros() as usize;
+        bitboard &= bitboard - 1;
+        Some(square)
+    })
+}
+
+/// Bitboard representation of a position, built for fast move generation.
+/// Moves are made by copying the position, so there is nothing to unmake.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BitboardPosition {
+    pieces: [[Bitboard; 6]; 2],
+    occupancy: [Bitboard; 2],
+    /// Zobrist key of the piece placement alone, updated as pieces are put and removed.
+    pieces_key: u64,
+    pub side_to_move: PieceColor,
+    pub castling_rights: CastlingRights,
+    pub en_passant_target: Option<usize>,
+    pub halfmove_clock: u32,
+}
+
+impl From<&Board> for BitboardPosition {
+    fn from(board: &Board) -> Self {
+        let mut position = Self {
+            pieces: [[0; 6]; 2],
+            occupancy: [0; 2],
+            pieces_key: 0,
+            side_to_move: board.on_turn,
+            castling_rights: board.castling_rights,
+            en_passant_target: board.en_passant_target.as_ref().map(square_of),
+            halfmove_clock: board.halfmove_clock,
+        };
+
+        board
+            .board
+            .iter()
+            .flatten()
+            .enumerate()
+            .for_each(|(square, cell)| {
+                if let Some(piece) = cell {
+                    position.put_piece(*piece, square);
+                }
+            });
+
+        position
+    }
+}
+
+impl BitboardPosition {
+    pub fn piece_at(&self, square: usize) -> Option<Piece> {
+        let mask = 1 << square;
+
+        [PieceColor::White, PieceColor::Black]
+            .into_iter()
+            .filter(|color| self.occupancy[color_index(*color)] & mask != 0)
+            .find_map(|color| {
+                PIECE_TYPES
+                    .into_iter()
+                    .find(|piece_type| {
+                        self.pieces[color_index(color)][piece_index(*piece_type)] & mask != 0
+                    })
+                    .map(|piece_type| Piece::new(piece_type, color))
+            })
+    }
+
+    fn put_piece(&mut self, piece: Piece, square: usize) {
+        let color = color_index(piece.piece_color);
+        self.pieces[color][piece_index(piece.piece_type)] |= 1 << square;
+        self.occupancy[color] |= 1 << square;
+        self.pieces_key ^= zobrist::piece_key(&piece, &position_of(square));
+    }
+
+    fn remove_piece(&mut self, piece: Piece, square: usize) {
+        let color = color_index(piece.piece_color);
+        self.pieces[color][piece_index(piece.piece_type)] &= !(1 << square);
+        self.occupancy[color] &= !(1 << square);
+        self.pieces_key ^= zobrist::piece_key(&piece, &position_of(square));
+    }
+
+    /// Zobrist key of the position, matching `Board::zobrist_key` for the same position.
+    pub fn zobrist_key(&self) -> u64 {
+        // Like the board, only hash the en passant file when a capture is possible
+        let en_passant_file = self.en_passant_target.filter(|&target| {
+            let capturers = pawn_attacks(color_index(self.side_to_move.opposite()), target);
+            capturers & self.pieces_of(self.side_to_move, PieceType::Pawn) != 0
+        });
+
+        self.pieces_key
+            ^ zobrist::side_to_move_key(self.side_to_move)
+            ^ zobrist::castling_rights_key(&self.castling_rights)
+            ^ zobrist::en_passant_key(en_passant_file.map(|target| target % 8))
+    }
+
+    fn pieces_of(&self, color: PieceColor, piece_type: PieceType) -> Bitboard {
+        self.pieces[color_index(color)][piece_index(piece_type)]
+    }
+
+    fn occupied(&self) -> Bitboard {
+        self.occupancy[0] | self.occupancy[1]
+    }
+
+    pub fn is_square_attacked(&self, square: usize, attacker_color: PieceColor) -> bool {
+        let occupied = self.occupied();
+        let queens = self.pieces_of(attacker_color, PieceType::Queen);
+
+        // A pawn of the attacker stands where a defending pawn on `square` would capture
+        pawn_attacks(color_index(attacker_color.opposite()), square)
+            & self.pieces_of(attacker_color, PieceType::Pawn)
+            != 0
+            || knight_attacks(square) & self.pieces_of(attacker_color, PieceType::Knight) != 0
+            || king_attacks(square) & self.pieces_of(attacker_color, PieceType::King) != 0
+            || bishop_attacks(square, occupied)
+                & (self.pieces_of(attacker_color, PieceType::Bishop) | queens)
+                != 0
+            || rook_attacks(square, occupied)
+                & (self.pieces_of(attacker_color, PieceType::Rook) | queens)
+                != 0
+    }
+
+    pub fn is_king_attacked(&self, color: PieceColor) -> bool {
+        squares(self.pieces_of(color, PieceType::King))
+            .next()
+            .is_some_and(|square| self.is_square_attacked(square, color.opposite()))
+    }
+
+    /// Moves that obey piece movement rules but may leave the own king in check.
+    /// Castling is only generated when the king does not start in or pass through check.
+    pub fn pseudo_legal_moves(&self) -> Vec<ChessMove> {
+        let color = self.side_to_move;
+        let own = self.occupancy[color_index(color)];
+        let enemy = self.occupancy[color_index(color.opposite())];
+        let occupied = own | enemy;
+        let mut moves = Vec::with_capacity(64);
+
+        self.generate_pawn_moves(&mut moves, enemy, occupied);
+
+        for piece_type in [
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Rook,
+            PieceType::Queen,
+            PieceType::King,
+        ] {
+            for from in squares(self.pieces_of(color, piece_type)) {
+                let attacks = match piece_type {
+                    PieceType::Knight => knight_attacks(from),
+                    PieceType::Bishop => bishop_attacks(from, occupied),
+                    PieceType::Rook => rook_attacks(from, occupied),
+                    PieceType::Queen => queen_attacks(from, occupied),
+                    _ => king_attacks(from),
+                };
+
+                for to in squares(attacks & !own) {
+                    let kind = if enemy & (1 << to) != 0 {
+                        MoveKind::Capture
+                    } else {
+                        MoveKind::Quiet
+                    };
+                    moves.push(ChessMove::new(position_of(from), position_of(to), kind));
+                }
+            }
+        }
+
+        self.generate_castling_moves(&mut moves, occupied);
+
+        moves
+    }
+
+    fn generate_pawn_moves(&self, moves: &mut Vec<ChessMove>, enemy: Bitboard, occupied: Bitboard) {
+        let color = self.side_to_move;
+        let (forward, start_row): (isize, usize) = match color {
+            PieceColor::White => (-8, 6),
+            PieceColor::Black => (8, 1),
+        };
+
+        for from in squares(self.pieces_of(color, PieceType::Pawn)) {
+            let to = from as isize + forward;
+            if !(0..64).contains(&to) {
+                continue;
+            }
+            let to = to as usize;
+
+            if occupied & (1 << to) == 0 {
+                Self::push_pawn_move(moves, from, to, MoveKind::Quiet);
+
+                let double_to = (to as isize + forward) as usize;
+                if from / 8 == start_row && occupied & (1 << double_to) == 0 {
+                    moves.push(ChessMove::new(
+                        position_of(from),
+                        position_of(double_to),
+                        MoveKind::DoublePawnPush,
+                    ));
+                }
+            }
+
+            let attacks = pawn_attacks(color_index(color), from);
+            for to in squares(attacks & enemy) {
+                Self::push_pawn_move(moves, from, to, MoveKind::Capture);
+            }
+
+            if let Some(target) = self.en_passant_target {
+                if attacks & (1 << target) != 0 {
+                    moves.push(ChessMove::new(
+                        position_of(from),
+                        position_of(target),
+                        MoveKind::EnPassant,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn push_pawn_move(moves: &mut Vec<ChessMove>, from: usize, to: usize, kind: MoveKind) {
+        if to / 8 == 0 || to / 8 == 7 {
+            for promotion in PROMOTION_PIECES {
+                moves.push(ChessMove {
+                    from: position_of(from),
+                    to: position_of(to),
+                    promotion: Some(promotion),
+                    kind,
+                });
+            }
+        } else {
+            moves.push(ChessMove::new(position_of(from), position_of(to), kind));
+        }
+    }
+
+    fn generate_castling_moves(&self, moves: &mut Vec<ChessMove>, occupied: Bitboard) {
+        let color = self.side_to_move;
+        let row = home_row(color);
+        let king_square = row * 8 + 4;
+
+        if self.pieces_of(color, PieceType::King) & (1 << king_square) == 0
+            || self.is_square_attacked(king_square, color.opposite())
+        {
+            return;
+        }
+
+        for side in [CastlingSide::KingSide, CastlingSide::QueenSide] {
+            let rook_square = row * 8 + side.rook_from_column();
+            if !self.castling_rights.can_castle(color, side)
+                || self.pieces_of(color, PieceType::Rook) & (1 << rook_square) == 0
+            {
+                continue;
+            }
+
+            let (start, end) = if rook_square > king_square {
+                (king_square + 1, rook_square)
+            } else {
+                (rook_square + 1, king_square)
+            };
+            let is_path_clear = (start..end).all(|square| occupied & (1 << square) == 0);
+
+            let transit_square = (king_square + row * 8 + side.king_to_column()) / 2;
+            if is_path_clear && !self.is_square_attacked(transit_square, color.opposite()) {
+                moves.push(ChessMove::new(
+                    position_of(king_square),
+                    position_of(row * 8 + side.king_to_column()),
+                    MoveKind::Castling(side),
+                ));
+            }
+        }
+    }
+
+    pub fn legal_moves(&self) -> Vec<ChessMove> {
+        let color = self.side_to_move;
+
+        self.pseudo_legal_moves()
+            .into_iter()
+            .filter(|chess_move| {
+                let mut next = *self;
+                next.make_move(chess_move);
+                !next.is_king_attacked(color)
+            })
+            .collect()
+    }
+
+    /// Applies a pseudo-legal move generated for this position.
+    pub fn make_move(&mut self, chess_move: &ChessMove) {
+        let from = square_of(&chess_move.from);
+        let to = square_of(&chess_move.to);
+        let Some(moving_piece) = self.piece_at(from) else {
+            return;
+        };
+
+        let captured_square = match chess_move.kind {
+            MoveKind::EnPassant => (from / 8) * 8 + to % 8,
+            _ => to,
+        };
+        let captured_piece = self.piece_at(captured_square);
+
+        if moving_piece.piece_type == PieceType::Pawn || captured_piece.is_some() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+
+        if let Some(captured_piece) = captured_piece {
+            self.remove_piece(captured_piece, captured_square);
+        }
+
+        self.remove_piece(moving_piece, from);
+        match chess_move.promotion {
+            Some(piece_type) => {
+                self.put_piece(Piece::new(piece_type, moving_piece.piece_color), to)
+            }
+            None => self.put_piece(moving_piece, to),
+        }
+
+        if let MoveKind::Castling(side) = chess_move.kind {
+            let row = from / 8;
+            let rook = Piece::new(PieceType::Rook, moving_piece.piece_color);
+            self.remove_piece(rook, row * 8 + side.rook_from_column());
+            self.put_piece(rook, row * 8 + side.rook_to_column());
+        }
+
+        self.en_passant_target = match chess_move.kind {
+            MoveKind::DoublePawnPush => Some((from + to) / 2),
+            _ => None,
+        };
+
+        if moving_piece.piece_type == PieceType::King {
+            self.castling_rights.revoke_all(moving_piece.piece_color);
+        }
+        self.castling_rights.update_for_square(&chess_move.from);
+        self.castling_rights.update_for_square(&chess_move.to);
+
+        self.side_to_move = self.side_to_move.opposite();
+    }
+}
+
+impl ChessPosition for BitboardPosition {
+    fn side_to_move(&self) -> PieceColor {
+        self.side_to_move
+    }
+
+    fn legal_moves(&self) -> Vec<ChessMove> {
+        BitboardPosition::legal_moves(self)
+    }
+
+    fn make_move(&mut self, chess_move: &ChessMove) {
+        BitboardPosition::make_move(self, chess_move)
+    }
+
+    fn is_in_check(&self) -> bool {
+        self.is_king_attacked(self.side_to_move)
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        BitboardPosition::zobrist_key(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(moves: &[ChessMove]) -> Vec<String> {
+        let mut descriptions: Vec<String> = moves
+            .iter()
+            .map(|chess_move| format!("{} {:?}", chess_move, chess_move.kind))
+            .collect();
+        descriptions.sort();
+        descriptions
+    }
+
+    #[test]
+    fn test_initial_position_moves() {
+        let position = BitboardPosition::from(&Board::default());
+
+        assert_eq!(position.legal_moves().len(), 20);
+        assert!(!position.is_in_check());
+    }
+
+    #[test]
+    fn test_piece_at_matches_board() {
+        let board = Board::default();
+        let position = BitboardPosition::from(&board);
+
+        for (square, cell) in board.board.iter().flatten().enumerate() {
+            let piece = position.piece_at(square);
+            assert_eq!(piece.map(|p| p.fen_char()), cell.map(|p| p.fen_char()));
+        }
+    }
+
+    #[test]
+    fn test_moves_match_board_along_a_game() {
+        let mut board = Board::default();
+        let mut position = BitboardPosition::from(&board);
+
+        for ply in 0..80 {
+            let board_moves = board.legal_moves();
+            let position_moves = position.legal_moves();
+
+            assert_eq!(
+                describe(&board_moves),
+                describe(&position_moves),
+                "ply {}",
+                ply
+            );
+
+            if board_moves.is_empty() || board.result.is_over() {
+                break;
+            }
+
+            let chess_move = &board_moves[(ply * 7 + 3) % board_moves.len()];
+            assert!(board.play_move(chess_move));
+            position.make_move(chess_move);
+
+            assert_eq!(position, BitboardPosition::from(&board));
+            assert_eq!(position.zobrist_key(), board.zobrist_key());
+        }
+    }
+
+    #[test]
+    fn test_sliding_attacks_stop_at_blockers() {
+        let mut board = Board::init_empty();
+        board.board[4][4] = Some(Piece::new(PieceType::Rook, PieceColor::White));
+        board.board[4][6] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
+        board.board[2][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        let position = BitboardPosition::from(&board);
+
+        let attacks = rook_attacks(square_of(&Position { x: 4, y: 4 }), position.occupied());
+
+        assert_eq!(attacks.count_ones(), 11);
+        assert!(attacks & (1 << square_of(&Position { x: 4, y: 6 })) != 0);
+        assert!(attacks & (1 << square_of(&Position { x: 4, y: 7 })) == 0);
+        assert!(attacks & (1 << square_of(&Position { x: 2, y: 4 })) != 0);
+        assert!(attacks & (1 << square_of(&Position { x: 1, y: 4 })) == 0);
+    }
+}
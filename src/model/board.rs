@@ -1,14 +1,34 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
 
-use super::{Direction, Piece, PieceColor, PieceType, Position};
+use super::{
+    promotion::PROMOTION_PIECES, zobrist, CastlingRights, CastlingSide, ChessMove, ChessPosition,
+    Direction, DrawReason, GameResult, MoveKind, Piece, PieceColor, PieceType, Position, UndoInfo,
+    WinReason,
+};
 
 #[derive(Debug, Clone)]
 pub struct Board {
+    /// Squares indexed by `Position`. Editing them directly leaves the cached
+    /// Zobrist key stale, so repetition detection only sees moves made through
+    /// `make_move`; `Hash` recomputes the key and stays consistent with `PartialEq`.
     pub board: [[Option<Piece>; 8]; 8],
     pub on_turn: PieceColor,
     pub cursor_position: Position,
     pub currently_legal_moves: Option<Vec<Position>>,
     pub is_check: bool,
+    pub castling_rights: CastlingRights,
+    pub en_passant_target: Option<Position>,
+    pub result: GameResult,
+    /// Plies since the last capture or pawn move, for the fifty-move rule.
+    pub halfmove_clock: u32,
+    /// Starts at 1 and is incremented after each move by black.
+    pub fullmove_number: u32,
+    zobrist_key: u64,
+    position_history: Vec<u64>,
+    move_history: Vec<UndoInfo>,
     captured_pieces: Vec<Piece>,
     pub selected_position: Option<Position>,
     pub debugger: Vec<String>,
@@ -58,53 +78,268 @@ impl Board {
         }
     }
 
-    pub fn is_getting_checked(&mut self) -> bool {
-        let current_player_color = self.on_turn;
-        let mut opponent_king_position: Position = Position { x: 0, y: 0 };
+    pub fn is_getting_checked(&self) -> bool {
+        self.is_king_attacked(self.on_turn.opposite())
+    }
 
-        let mut is_check = false;
+    /// Returns true if the king of the given color is attacked by any opposing piece.
+    pub fn is_king_attacked(&self, color: PieceColor) -> bool {
+        match self.find_king(color) {
+            Some(king_position) => self.is_square_attacked(&king_position, color.opposite()),
+            None => false,
+        }
+    }
 
-        // Locate opponent's king
-        self.board.iter().enumerate().for_each(|(x, row)| {
-            row.iter().enumerate().for_each(|(y, cell)| {
-                if let Some(piece) = cell {
-                    if piece.piece_type == PieceType::King
-                        && piece.piece_color != current_player_color
-                    {
-                        opponent_king_position = Position { x, y };
-                    }
+    fn find_king(&self, color: PieceColor) -> Option<Position> {
+        self.board.iter().enumerate().find_map(|(x, row)| {
+            row.iter().enumerate().find_map(|(y, cell)| match cell {
+                Some(piece)
+                    if piece.piece_type == PieceType::King && piece.piece_color == color =>
+                {
+                    Some(Position { x, y })
                 }
+                _ => None,
             })
-        });
+        })
+    }
+
+    /// Expands the pseudo-legal targets of the piece in `from` into fully described moves.
+    fn get_pseudo_legal_moves(&self, from: &Position) -> Vec<ChessMove> {
+        let Some(piece) = self.board[from.x][from.y] else {
+            return vec![];
+        };
+        let Some(targets) = piece.piece_type.legal_moves(self, from) else {
+            return vec![];
+        };
+
+        let is_pawn = piece.piece_type == PieceType::Pawn;
+        let mut moves = Vec::with_capacity(targets.len());
+
+        for to in targets {
+            let kind = if piece.piece_type == PieceType::King && from.y.abs_diff(to.y) == 2 {
+                if to.y > from.y {
+                    MoveKind::Castling(CastlingSide::KingSide)
+                } else {
+                    MoveKind::Castling(CastlingSide::QueenSide)
+                }
+            } else if is_pawn && from.y != to.y && self.is_cell_empty(to.clone()) {
+                MoveKind::EnPassant
+            } else if is_pawn && from.x.abs_diff(to.x) == 2 {
+                MoveKind::DoublePawnPush
+            } else if self.is_cell_empty(to.clone()) {
+                MoveKind::Quiet
+            } else {
+                MoveKind::Capture
+            };
+
+            if is_pawn && (to.x == 0 || to.x == 7) {
+                for promotion in PROMOTION_PIECES {
+                    moves.push(ChessMove {
+                        from: from.clone(),
+                        to: to.clone(),
+                        promotion: Some(promotion),
+                        kind,
+                    });
+                }
+            } else {
+                moves.push(ChessMove::new(from.clone(), to, kind));
+            }
+        }
+
+        moves
+    }
+
+    /// Whether a pseudo-legal move keeps the mover's own king out of check.
+    fn is_legal(&self, chess_move: &ChessMove) -> bool {
+        let Some(piece) = self.board[chess_move.from.x][chess_move.from.y] else {
+            return false;
+        };
+
+        // A castling king may neither start from nor pass through check
+        if let MoveKind::Castling(_) = chess_move.kind {
+            let transit_position = Position {
+                x: chess_move.from.x,
+                y: (chess_move.from.y + chess_move.to.y) / 2,
+            };
+            if self.is_king_attacked(piece.piece_color)
+                || self.is_square_attacked(&transit_position, piece.piece_color.opposite())
+            {
+                return false;
+            }
+        }
 
         let mut simulated_board = self.clone();
+        simulated_board.move_pieces(chess_move);
+        !simulated_board.is_king_attacked(piece.piece_color)
+    }
 
-        // Determine whether king gets attacked
-        simulated_board
-            .board
+    /// Legal moves of the piece in `from`, empty unless it belongs to the side to move.
+    pub fn moves_from(&self, from: &Position) -> Vec<ChessMove> {
+        match self.board[from.x][from.y] {
+            Some(piece) if piece.piece_color == self.on_turn => self
+                .get_pseudo_legal_moves(from)
+                .into_iter()
+                .filter(|chess_move| self.is_legal(chess_move))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// All legal moves for the side to move.
+    pub fn legal_moves(&self) -> Vec<ChessMove> {
+        (0..8)
+            .flat_map(|x| (0..8).map(move |y| Position { x, y }))
+            .flat_map(|from| self.moves_from(&from))
+            .collect()
+    }
+
+    /// Whether the side to move has at least one fully legal move.
+    pub fn has_legal_moves(&self) -> bool {
+        (0..8)
+            .flat_map(|x| (0..8).map(move |y| Position { x, y }))
+            .any(|from| !self.moves_from(&from).is_empty())
+    }
+
+    /// Looks up the legal move between two squares, with `promotion` telling
+    /// apart the four promotion moves of a pawn.
+    pub fn find_move(
+        &self,
+        from: &Position,
+        to: &Position,
+        promotion: Option<PieceType>,
+    ) -> Option<ChessMove> {
+        self.moves_from(from)
             .into_iter()
+            .find(|chess_move| chess_move.to == *to && chess_move.promotion == promotion)
+    }
+
+    fn update_result(&mut self) {
+        if !self.has_legal_moves() {
+            self.result = if self.is_check {
+                GameResult::win_for(self.on_turn.opposite(), WinReason::Checkmate)
+            } else {
+                GameResult::Draw(DrawReason::Stalemate)
+            };
+        } else if self.is_insufficient_material() {
+            self.result = GameResult::Draw(DrawReason::InsufficientMaterial);
+        } else if self.repetition_count() >= 3 {
+            self.result = GameResult::Draw(DrawReason::ThreefoldRepetition);
+        } else if self.halfmove_clock >= 100 {
+            self.result = GameResult::Draw(DrawReason::FiftyMoveRule);
+        }
+    }
+
+    /// File of the en passant target, if the side to move has a pawn that can
+    /// actually capture there. Positions only differ by this when it is `Some`.
+    pub fn capturable_en_passant_file(&self) -> Option<usize> {
+        let target = self.en_passant_target.as_ref()?;
+
+        // The pawn that could capture sits on the same row as the pawn that double moved
+        let capture_row = match self.on_turn {
+            PieceColor::White => target.x + 1,
+            PieceColor::Black => target.x.checked_sub(1)?,
+        };
+        if capture_row > 7 {
+            return None;
+        }
+
+        let can_capture = [target.y.checked_sub(1), Some(target.y + 1)]
+            .into_iter()
+            .flatten()
+            .filter(|&y| y < 8)
+            .any(|y| {
+                matches!(
+                    self.board[capture_row][y],
+                    Some(piece) if piece.piece_type == PieceType::Pawn
+                        && piece.piece_color == self.on_turn
+                )
+            });
+
+        can_capture.then_some(target.y)
+    }
+
+    /// Zobrist key of the current position, maintained incrementally by `make_move`.
+    pub fn zobrist_key(&self) -> u64 {
+        self.zobrist_key
+    }
+
+    /// Computes the Zobrist key from scratch. Needed after editing `board` directly.
+    pub fn compute_zobrist_key(&self) -> u64 {
+        let pieces_key = self
+            .board
+            .iter()
             .enumerate()
-            .for_each(|(i, row)| {
-                row.iter().enumerate().for_each(|(j, cell)| {
-                    if let Some(piece) = cell {
-                        simulated_board.select_position(&Position { x: i, y: j });
-                        if let Some(legal_moves) = piece.piece_type.legal_moves(&simulated_board) {
-                            if legal_moves.contains(&opponent_king_position) {
-                                is_check = true;
-                            }
+            .flat_map(|(x, row)| row.iter().enumerate().map(move |(y, cell)| (x, y, cell)))
+            .filter_map(|(x, y, cell)| {
+                cell.map(|piece| zobrist::piece_key(&piece, &Position { x, y }))
+            })
+            .fold(0, |key, piece_key| key ^ piece_key);
+
+        pieces_key
+            ^ zobrist::side_to_move_key(self.on_turn)
+            ^ zobrist::castling_rights_key(&self.castling_rights)
+            ^ zobrist::en_passant_key(self.capturable_en_passant_file())
+    }
+
+    fn repetition_count(&self) -> usize {
+        self.position_history
+            .iter()
+            .filter(|key| **key == self.zobrist_key)
+            .count()
+    }
+
+    /// Dead positions where no sequence of legal moves can lead to checkmate.
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut minor_pieces = 0;
+        let mut bishop_square_colors = Vec::with_capacity(4);
+
+        for (x, row) in self.board.iter().enumerate() {
+            for (y, cell) in row.iter().enumerate() {
+                if let Some(piece) = cell {
+                    match piece.piece_type {
+                        PieceType::King => {}
+                        PieceType::Knight => minor_pieces += 1,
+                        PieceType::Bishop => {
+                            minor_pieces += 1;
+                            bishop_square_colors.push((x + y) % 2);
                         }
+                        PieceType::Pawn | PieceType::Rook | PieceType::Queen => return false,
                     }
-                })
-            });
-        is_check
+                }
+            }
+        }
+
+        let bishops_on_same_color = bishop_square_colors.len() == minor_pieces
+            && bishop_square_colors
+                .iter()
+                .all(|color| *color == bishop_square_colors[0]);
+
+        minor_pieces <= 1 || bishops_on_same_color
     }
 
     pub fn select_position(&mut self, position: &Position) {
+        if self.result.is_over() {
+            self.selected_position = None;
+            self.currently_legal_moves = None;
+            return;
+        }
+
         match self.board[position.x][position.y] {
             Some(piece) => {
                 if piece.piece_color == self.on_turn {
+                    let mut targets: Vec<Position> = Vec::with_capacity(27);
+                    for chess_move in self.moves_from(position) {
+                        if !targets.contains(&chess_move.to) {
+                            targets.push(chess_move.to);
+                        }
+                    }
+
                     self.selected_position = Some(position.clone());
-                    self.currently_legal_moves = piece.piece_type.legal_moves(self);
+                    self.currently_legal_moves = if targets.is_empty() {
+                        None
+                    } else {
+                        Some(targets)
+                    };
                 } else {
                     self.currently_legal_moves = None;
                 }
@@ -116,46 +351,282 @@ impl Board {
         }
     }
 
+    /// Whether moving the selected piece to `to` would promote a pawn.
+    pub fn is_promotion_move(&self, to: &Position) -> bool {
+        let Some(from) = &self.selected_position else {
+            return false;
+        };
+
+        matches!(
+            self.board[from.x][from.y],
+            Some(piece) if piece.piece_type == PieceType::Pawn && (to.x == 0 || to.x == 7)
+        )
+    }
+
+    /// Moves the selected piece to `to`, promoting a pawn reaching the last rank to a queen.
     pub fn move_piece(&mut self, to: &Position) {
-        if let Some(from) = &self.selected_position {
-            if let Some(selected_piece) = self.board[from.x][from.y] {
-                if let Some(legal_moves) = &self.currently_legal_moves {
-                    if legal_moves.contains(to) {
-                        // Set current position to empty
-                        self.board[from.x][from.y] = None;
-
-                        // If capture, add the captured piece to captured_pieces
-                        if let Some(piece) = self.board[to.x][to.y] {
-                            self.captured_pieces.push(piece);
-                        }
-                        // Place selected piece to new position
-                        self.board[to.x][to.y] = Some(selected_piece);
+        self.move_piece_with_promotion(to, None);
+    }
 
-                        self.selected_position = None;
-                        self.currently_legal_moves = None;
+    /// Moves the selected piece to `to`. A pawn reaching the last rank becomes
+    /// `promotion` (a queen if not given); kings and pawns are rejected as choices.
+    pub fn move_piece_with_promotion(&mut self, to: &Position, promotion: Option<PieceType>) {
+        let Some(from) = self.selected_position.clone() else {
+            return;
+        };
 
-                        if self.is_getting_checked() {
-                            self.is_check = true;
-                        }
+        let promotion = if self.is_promotion_move(to) {
+            Some(promotion.unwrap_or(PieceType::Queen))
+        } else {
+            None
+        };
 
-                        self.switch_turn();
-                    }
-                }
+        if let Some(chess_move) = self.find_move(&from, to, promotion) {
+            self.play_move(&chess_move);
+        }
+    }
+
+    /// Plays a move for the side to move if it is legal and the game is still
+    /// ongoing. Only `from`, `to` and `promotion` are taken into account.
+    pub fn play_move(&mut self, chess_move: &ChessMove) -> bool {
+        if self.result.is_over() {
+            return false;
+        }
+
+        match self.find_move(&chess_move.from, &chess_move.to, chess_move.promotion) {
+            Some(legal_move) => {
+                self.make_move(&legal_move);
+                true
             }
+            None => false,
         }
     }
 
-    fn switch_turn(&mut self) {
-        match self.on_turn {
-            PieceColor::White => self.on_turn = PieceColor::Black,
-            PieceColor::Black => self.on_turn = PieceColor::White,
+    /// Treats the current position as the start of a new game, e.g. after loading a FEN.
+    pub(super) fn reset_history(&mut self) {
+        self.zobrist_key = self.compute_zobrist_key();
+        self.position_history.clear();
+        self.position_history.push(self.zobrist_key);
+        self.move_history.clear();
+        self.captured_pieces.clear();
+        self.selected_position = None;
+        self.currently_legal_moves = None;
+        self.is_check = self.is_king_attacked(self.on_turn);
+        self.result = GameResult::Ongoing;
+        self.update_result();
+    }
+
+    /// Moves that have been made on this board, oldest first.
+    pub fn move_history(&self) -> &[UndoInfo] {
+        &self.move_history
+    }
+
+    /// Pieces taken so far, in the order they were captured.
+    pub fn captured_pieces(&self) -> &[Piece] {
+        &self.captured_pieces
+    }
+
+    /// Applies a move taken from `legal_moves`, recording what is needed to unmake it.
+    pub fn make_move(&mut self, chess_move: &ChessMove) {
+        let (from, to) = (&chess_move.from, &chess_move.to);
+        let Some(moving_piece) = self.board[from.x][from.y] else {
+            return;
+        };
+
+        let mut undo_info = UndoInfo {
+            chess_move: chess_move.clone(),
+            moved_piece: moving_piece,
+            captured_piece: None,
+            castling_rights: self.castling_rights,
+            en_passant_target: self.en_passant_target.clone(),
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+            is_check: self.is_check,
+            result: self.result,
+            zobrist_key: self.zobrist_key,
+        };
+
+        // Rights and en passant file are swapped out here and back in once updated
+        self.zobrist_key ^= zobrist::castling_rights_key(&self.castling_rights)
+            ^ zobrist::en_passant_key(self.capturable_en_passant_file());
+
+        if moving_piece.piece_type == PieceType::Pawn || chess_move.is_capture() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+
+        if moving_piece.piece_color == PieceColor::Black {
+            self.fullmove_number += 1;
+        }
+
+        if let Some(captured_piece) = self.move_pieces(chess_move) {
+            self.captured_pieces.push(captured_piece);
+            undo_info.captured_piece = Some(captured_piece);
         }
+        self.move_history.push(undo_info);
+
+        // A double pawn move can be captured en passant on the next ply only
+        self.en_passant_target = match chess_move.kind {
+            MoveKind::DoublePawnPush => Some(Position {
+                x: (from.x + to.x) / 2,
+                y: from.y,
+            }),
+            _ => None,
+        };
+
+        if moving_piece.piece_type == PieceType::King {
+            self.castling_rights.revoke_all(moving_piece.piece_color);
+        }
+        self.castling_rights.update_for_square(from);
+        self.castling_rights.update_for_square(to);
+
+        self.selected_position = None;
+        self.currently_legal_moves = None;
+
+        self.is_check = self.is_getting_checked();
+
+        self.switch_turn();
+        self.zobrist_key ^= zobrist::side_to_move_key(PieceColor::White)
+            ^ zobrist::side_to_move_key(PieceColor::Black)
+            ^ zobrist::castling_rights_key(&self.castling_rights)
+            ^ zobrist::en_passant_key(self.capturable_en_passant_file());
+        self.position_history.push(self.zobrist_key);
+        self.update_result();
+    }
+
+    /// Takes back the last made move, restoring the exact previous state.
+    /// Returns the unmade move, or `None` if no move has been made.
+    pub fn unmake_move(&mut self) -> Option<ChessMove> {
+        let undo_info = self.move_history.pop()?;
+        let (from, to) = (&undo_info.chess_move.from, &undo_info.chess_move.to);
+
+        self.board[to.x][to.y] = None;
+        self.board[from.x][from.y] = Some(undo_info.moved_piece);
+
+        if let Some(captured_piece) = undo_info.captured_piece {
+            match undo_info.chess_move.kind {
+                MoveKind::EnPassant => self.board[from.x][to.y] = Some(captured_piece),
+                _ => self.board[to.x][to.y] = Some(captured_piece),
+            }
+            self.captured_pieces.pop();
+        }
+
+        if let MoveKind::Castling(side) = undo_info.chess_move.kind {
+            let rook = self.board[from.x][side.rook_to_column()].take();
+            self.board[from.x][side.rook_from_column()] = rook;
+        }
+
+        self.castling_rights = undo_info.castling_rights;
+        self.en_passant_target = undo_info.en_passant_target;
+        self.halfmove_clock = undo_info.halfmove_clock;
+        self.fullmove_number = undo_info.fullmove_number;
+        self.is_check = undo_info.is_check;
+        self.result = undo_info.result;
+        self.zobrist_key = undo_info.zobrist_key;
+        self.position_history.pop();
+        self.switch_turn();
+
+        self.selected_position = None;
+        self.currently_legal_moves = None;
+
+        Some(undo_info.chess_move)
+    }
+
+    /// Relocates the pieces involved in a move, returning the captured piece.
+    /// En passant removes the pawn beside the moving one, castling moves the rook too.
+    fn move_pieces(&mut self, chess_move: &ChessMove) -> Option<Piece> {
+        let (from, to) = (&chess_move.from, &chess_move.to);
+        let moving_piece = self.board[from.x][from.y].take()?;
+
+        let captured_position = match chess_move.kind {
+            MoveKind::EnPassant => Position { x: from.x, y: to.y },
+            _ => to.clone(),
+        };
+        let captured_piece = self.board[captured_position.x][captured_position.y].take();
+
+        let placed_piece = match chess_move.promotion {
+            Some(piece_type) => Piece::new(piece_type, moving_piece.piece_color),
+            None => moving_piece,
+        };
+        self.board[to.x][to.y] = Some(placed_piece);
+
+        self.zobrist_key ^=
+            zobrist::piece_key(&moving_piece, from) ^ zobrist::piece_key(&placed_piece, to);
+        if let Some(captured_piece) = &captured_piece {
+            self.zobrist_key ^= zobrist::piece_key(captured_piece, &captured_position);
+        }
+
+        if let MoveKind::Castling(side) = chess_move.kind {
+            let rook_from = Position {
+                x: from.x,
+                y: side.rook_from_column(),
+            };
+            let rook_to = Position {
+                x: from.x,
+                y: side.rook_to_column(),
+            };
+            if let Some(rook) = self.board[rook_from.x][rook_from.y].take() {
+                self.board[rook_to.x][rook_to.y] = Some(rook);
+                self.zobrist_key ^=
+                    zobrist::piece_key(&rook, &rook_from) ^ zobrist::piece_key(&rook, &rook_to);
+            }
+        }
+
+        captured_piece
+    }
+
+    fn switch_turn(&mut self) {
+        self.on_turn = self.on_turn.opposite();
+    }
+}
+
+/// Boards are equal when they describe the same position for repetition
+/// purposes, regardless of history, cursor or selection.
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.board == other.board
+            && self.on_turn == other.on_turn
+            && self.castling_rights == other.castling_rights
+            && self.capturable_en_passant_file() == other.capturable_en_passant_file()
+    }
+}
+
+impl Eq for Board {}
+
+/// Hashes the key computed from scratch rather than the cached one, which is
+/// stale after `board` was edited directly.
+impl Hash for Board {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.compute_zobrist_key());
+    }
+}
+
+impl ChessPosition for Board {
+    fn side_to_move(&self) -> PieceColor {
+        self.on_turn
+    }
+
+    fn legal_moves(&self) -> Vec<ChessMove> {
+        Board::legal_moves(self)
+    }
+
+    fn make_move(&mut self, chess_move: &ChessMove) {
+        Board::make_move(self, chess_move);
+    }
+
+    fn is_in_check(&self) -> bool {
+        self.is_king_attacked(self.on_turn)
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        self.zobrist_key
     }
 }
 
 impl Default for Board {
     fn default() -> Self {
-        Self {
+        let mut board = Self {
             board: [
                 [
                     Some(Piece::new(PieceType::Rook, PieceColor::Black)),
@@ -207,9 +678,20 @@ impl Default for Board {
             selected_position: None,
             currently_legal_moves: None,
             is_check: false,
+            castling_rights: CastlingRights::default(),
+            en_passant_target: None,
+            result: GameResult::Ongoing,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            zobrist_key: 0,
+            position_history: Vec::with_capacity(128),
+            move_history: Vec::with_capacity(128),
             captured_pieces: Vec::with_capacity(32),
             debugger: vec![],
-        }
+        };
+        board.zobrist_key = board.compute_zobrist_key();
+        board.position_history.push(board.zobrist_key);
+        board
     }
 }
 
@@ -223,8 +705,16 @@ impl Board {
             selected_position: None,
             currently_legal_moves: None,
             is_check: false,
+            castling_rights: CastlingRights::none(),
+            en_passant_target: None,
+            result: GameResult::Ongoing,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            zobrist_key: 0,
+            position_history: Vec::with_capacity(128),
+            move_history: Vec::with_capacity(128),
             captured_pieces: Vec::with_capacity(32),
-            debugger: vec![]
+            debugger: vec![],
         }
     }
 }
@@ -243,7 +733,7 @@ impl Display for Board {
         writeln!(f, "On turn: {:?}", self.on_turn)?;
         writeln!(f, "Cursor position: {:?}", self.cursor_position)?;
         writeln!(f, "Selected position: {:?}", self.selected_position)?;
-        writeln!(f, "Is check: {}", self.is_check)?; 
+        writeln!(f, "Is check: {}", self.is_check)?;
         writeln!(f, "Prints:")?;
         for line in &self.debugger {
             writeln!(f, "{}", line)?;
@@ -251,3 +741,692 @@ impl Display for Board {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn place(board: &mut Board, x: usize, y: usize, piece_type: PieceType, color: PieceColor) {
+        board.board[x][y] = Some(Piece::new(piece_type, color));
+    }
+
+    #[test]
+    fn test_pinned_rook_can_only_move_along_pin() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 5, 4, PieceType::Rook, PieceColor::White);
+        place(&mut board, 0, 4, PieceType::Rook, PieceColor::Black);
+        board.select_position(&Position { x: 5, y: 4 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        let expected_moves = [
+            Position { x: 6, y: 4 },
+            Position { x: 4, y: 4 },
+            Position { x: 3, y: 4 },
+            Position { x: 2, y: 4 },
+            Position { x: 1, y: 4 },
+            Position { x: 0, y: 4 },
+        ];
+
+        assert_eq!(legal_moves.len(), expected_moves.len());
+
+        for position in expected_moves.iter() {
+            assert!(legal_moves.contains(position));
+        }
+    }
+
+    #[test]
+    fn test_pinned_knight_has_no_moves() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 6, 3, PieceType::Knight, PieceColor::White);
+        place(&mut board, 4, 1, PieceType::Bishop, PieceColor::Black);
+        board.select_position(&Position { x: 6, y: 3 });
+
+        assert_eq!(board.selected_position, Some(Position { x: 6, y: 3 }));
+        assert!(board.currently_legal_moves.is_none());
+    }
+
+    #[test]
+    fn test_discovered_check_on_own_king_is_illegal() {
+        let mut board = Board::init_empty();
+        board.on_turn = PieceColor::Black;
+        place(&mut board, 0, 0, PieceType::King, PieceColor::Black);
+        place(&mut board, 2, 2, PieceType::Pawn, PieceColor::Black);
+        place(&mut board, 5, 5, PieceType::Queen, PieceColor::White);
+        board.select_position(&Position { x: 2, y: 2 });
+
+        assert!(board.currently_legal_moves.is_none());
+    }
+
+    #[test]
+    fn test_king_cannot_step_onto_attacked_square() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 0, 3, PieceType::Rook, PieceColor::Black);
+        place(&mut board, 5, 6, PieceType::Pawn, PieceColor::Black);
+        board.select_position(&Position { x: 7, y: 4 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        let expected_moves = [Position { x: 6, y: 4 }, Position { x: 7, y: 5 }];
+
+        assert_eq!(legal_moves.len(), expected_moves.len());
+
+        for position in expected_moves.iter() {
+            assert!(legal_moves.contains(position));
+        }
+    }
+
+    #[test]
+    fn test_king_cannot_capture_defended_piece() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 7, PieceType::King, PieceColor::White);
+        place(&mut board, 6, 6, PieceType::Knight, PieceColor::Black);
+        place(&mut board, 5, 5, PieceType::Bishop, PieceColor::Black);
+        board.select_position(&Position { x: 7, y: 7 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        assert!(!legal_moves.contains(&Position { x: 6, y: 6 }));
+    }
+
+    #[test]
+    fn test_king_cannot_retreat_along_checking_line() {
+        let mut board = Board::init_empty();
+        place(&mut board, 4, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 4, 0, PieceType::Rook, PieceColor::Black);
+        board.select_position(&Position { x: 4, y: 4 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        assert!(!legal_moves.contains(&Position { x: 4, y: 5 }));
+        assert!(!legal_moves.contains(&Position { x: 4, y: 3 }));
+        assert!(legal_moves.contains(&Position { x: 3, y: 4 }));
+    }
+
+    #[test]
+    fn test_check_must_be_resolved() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 7, 0, PieceType::Rook, PieceColor::White);
+        place(&mut board, 5, 2, PieceType::Knight, PieceColor::White);
+        place(&mut board, 0, 4, PieceType::Rook, PieceColor::Black);
+
+        board.select_position(&Position { x: 7, y: 0 });
+        assert!(board.currently_legal_moves.is_none());
+
+        board.select_position(&Position { x: 5, y: 2 });
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        let expected_moves = [Position { x: 6, y: 4 }, Position { x: 4, y: 4 }];
+
+        assert_eq!(legal_moves.len(), expected_moves.len());
+
+        for position in expected_moves.iter() {
+            assert!(legal_moves.contains(position));
+        }
+    }
+
+    fn castling_board() -> Board {
+        let mut board = Board::init_empty();
+        board.castling_rights = CastlingRights::default();
+        place(&mut board, 7, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 7, 0, PieceType::Rook, PieceColor::White);
+        place(&mut board, 7, 7, PieceType::Rook, PieceColor::White);
+        place(&mut board, 0, 4, PieceType::King, PieceColor::Black);
+        place(&mut board, 0, 0, PieceType::Rook, PieceColor::Black);
+        place(&mut board, 0, 7, PieceType::Rook, PieceColor::Black);
+        board
+    }
+
+    #[test]
+    fn test_castling_king_side_moves_rook() {
+        let mut board = castling_board();
+        board.select_position(&Position { x: 7, y: 4 });
+        board.move_piece(&Position { x: 7, y: 6 });
+
+        assert_eq!(board.board[7][6].unwrap().piece_type, PieceType::King);
+        assert_eq!(board.board[7][5].unwrap().piece_type, PieceType::Rook);
+        assert!(board.board[7][7].is_none());
+        assert!(!board.castling_rights.white_king_side);
+        assert!(!board.castling_rights.white_queen_side);
+        assert!(board.castling_rights.black_king_side);
+    }
+
+    #[test]
+    fn test_castling_queen_side_moves_rook() {
+        let mut board = castling_board();
+        board.on_turn = PieceColor::Black;
+        board.select_position(&Position { x: 0, y: 4 });
+        board.move_piece(&Position { x: 0, y: 2 });
+
+        assert_eq!(board.board[0][2].unwrap().piece_type, PieceType::King);
+        assert_eq!(board.board[0][3].unwrap().piece_type, PieceType::Rook);
+        assert!(board.board[0][0].is_none());
+    }
+
+    #[test]
+    fn test_cannot_castle_out_of_check() {
+        let mut board = castling_board();
+        place(&mut board, 4, 4, PieceType::Rook, PieceColor::Black);
+        board.select_position(&Position { x: 7, y: 4 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        assert!(!legal_moves.contains(&Position { x: 7, y: 6 }));
+        assert!(!legal_moves.contains(&Position { x: 7, y: 2 }));
+    }
+
+    #[test]
+    fn test_cannot_castle_through_or_into_attacked_square() {
+        let mut board = castling_board();
+        place(&mut board, 3, 5, PieceType::Rook, PieceColor::Black);
+        place(&mut board, 3, 2, PieceType::Rook, PieceColor::Black);
+        board.select_position(&Position { x: 7, y: 4 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        assert!(!legal_moves.contains(&Position { x: 7, y: 6 }));
+        assert!(!legal_moves.contains(&Position { x: 7, y: 2 }));
+    }
+
+    #[test]
+    fn test_queen_side_castling_allowed_when_only_b_file_attacked() {
+        let mut board = castling_board();
+        place(&mut board, 3, 1, PieceType::Rook, PieceColor::Black);
+        board.select_position(&Position { x: 7, y: 4 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        assert!(legal_moves.contains(&Position { x: 7, y: 2 }));
+    }
+
+    #[test]
+    fn test_castling_rights_lost_on_rook_move_and_capture() {
+        let mut board = castling_board();
+        board.select_position(&Position { x: 7, y: 7 });
+        board.move_piece(&Position { x: 0, y: 7 });
+
+        assert!(!board.castling_rights.white_king_side);
+        assert!(board.castling_rights.white_queen_side);
+        assert!(!board.castling_rights.black_king_side);
+        assert!(board.castling_rights.black_queen_side);
+    }
+
+    #[test]
+    fn test_double_pawn_move_sets_en_passant_target_for_one_ply() {
+        let mut board = Board::default();
+        board.select_position(&Position { x: 6, y: 4 });
+        board.move_piece(&Position { x: 4, y: 4 });
+
+        assert_eq!(board.en_passant_target, Some(Position { x: 5, y: 4 }));
+
+        board.select_position(&Position { x: 0, y: 6 });
+        board.move_piece(&Position { x: 2, y: 5 });
+
+        assert!(board.en_passant_target.is_none());
+    }
+
+    #[test]
+    fn test_en_passant_capture_removes_pawn() {
+        let mut board = Board::default();
+        board.select_position(&Position { x: 6, y: 4 });
+        board.move_piece(&Position { x: 4, y: 4 });
+        board.select_position(&Position { x: 1, y: 0 });
+        board.move_piece(&Position { x: 2, y: 0 });
+        board.select_position(&Position { x: 4, y: 4 });
+        board.move_piece(&Position { x: 3, y: 4 });
+        board.select_position(&Position { x: 1, y: 3 });
+        board.move_piece(&Position { x: 3, y: 3 });
+
+        board.select_position(&Position { x: 3, y: 4 });
+        assert!(board
+            .currently_legal_moves
+            .as_ref()
+            .unwrap()
+            .contains(&Position { x: 2, y: 3 }));
+
+        board.move_piece(&Position { x: 2, y: 3 });
+
+        assert_eq!(board.board[2][3].unwrap().piece_type, PieceType::Pawn);
+        assert!(board.board[3][3].is_none());
+        assert!(board.board[3][4].is_none());
+        assert_eq!(board.captured_pieces.len(), 1);
+        assert_eq!(board.captured_pieces[0].piece_color, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_en_passant_exposing_king_on_rank_is_illegal() {
+        let mut board = Board::init_empty();
+        place(&mut board, 3, 0, PieceType::King, PieceColor::White);
+        place(&mut board, 3, 1, PieceType::Pawn, PieceColor::White);
+        place(&mut board, 3, 2, PieceType::Pawn, PieceColor::Black);
+        place(&mut board, 3, 7, PieceType::Rook, PieceColor::Black);
+        board.en_passant_target = Some(Position { x: 2, y: 2 });
+        board.select_position(&Position { x: 3, y: 1 });
+
+        let legal_moves = board.currently_legal_moves.unwrap();
+
+        assert!(!legal_moves.contains(&Position { x: 2, y: 2 }));
+        assert!(legal_moves.contains(&Position { x: 2, y: 1 }));
+    }
+
+    fn promotion_board() -> Board {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 4, PieceType::King, PieceColor::White);
+        place(&mut board, 0, 0, PieceType::King, PieceColor::Black);
+        place(&mut board, 1, 6, PieceType::Pawn, PieceColor::White);
+        place(&mut board, 0, 7, PieceType::Rook, PieceColor::Black);
+        board
+    }
+
+    #[test]
+    fn test_promotion_to_chosen_piece() {
+        let mut board = promotion_board();
+        board.select_position(&Position { x: 1, y: 6 });
+
+        assert!(board.is_promotion_move(&Position { x: 0, y: 6 }));
+
+        board.move_piece_with_promotion(&Position { x: 0, y: 6 }, Some(PieceType::Knight));
+
+        let piece = board.board[0][6].unwrap();
+        assert_eq!(piece.piece_type, PieceType::Knight);
+        assert_eq!(piece.piece_color, PieceColor::White);
+        assert!(board.board[1][6].is_none());
+        assert_eq!(board.on_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_capture_promotion_defaults_to_queen() {
+        let mut board = promotion_board();
+        board.select_position(&Position { x: 1, y: 6 });
+        board.move_piece(&Position { x: 0, y: 7 });
+
+        assert_eq!(board.board[0][7].unwrap().piece_type, PieceType::Queen);
+        assert_eq!(board.captured_pieces[0].piece_type, PieceType::Rook);
+    }
+
+    #[test]
+    fn test_promotion_to_king_is_rejected() {
+        let mut board = promotion_board();
+        board.select_position(&Position { x: 1, y: 6 });
+        board.move_piece_with_promotion(&Position { x: 0, y: 6 }, Some(PieceType::King));
+
+        assert_eq!(board.board[1][6].unwrap().piece_type, PieceType::Pawn);
+        assert_eq!(board.on_turn, PieceColor::White);
+    }
+
+    fn play(board: &mut Board, from: (usize, usize), to: (usize, usize)) {
+        board.select_position(&Position {
+            x: from.0,
+            y: from.1,
+        });
+        board.move_piece(&Position { x: to.0, y: to.1 });
+    }
+
+    #[test]
+    fn test_fools_mate_is_checkmate() {
+        let mut board = Board::default();
+        play(&mut board, (6, 5), (5, 5));
+        play(&mut board, (1, 4), (3, 4));
+        play(&mut board, (6, 6), (4, 6));
+        play(&mut board, (0, 3), (4, 7));
+
+        assert!(board.is_check);
+        assert_eq!(board.result, GameResult::BlackWins(WinReason::Checkmate));
+    }
+
+    #[test]
+    fn test_check_flag_is_cleared() {
+        let mut board = Board::default();
+        play(&mut board, (6, 4), (4, 4));
+        play(&mut board, (1, 5), (2, 5));
+        play(&mut board, (7, 3), (3, 7));
+
+        assert!(board.is_check);
+        assert_eq!(board.result, GameResult::Ongoing);
+
+        play(&mut board, (1, 6), (2, 6));
+
+        assert!(!board.is_check);
+    }
+
+    #[test]
+    fn test_stalemate_is_draw() {
+        let mut board = Board::init_empty();
+        place(&mut board, 0, 0, PieceType::King, PieceColor::Black);
+        place(&mut board, 2, 1, PieceType::King, PieceColor::White);
+        place(&mut board, 3, 2, PieceType::Queen, PieceColor::White);
+        play(&mut board, (3, 2), (1, 2));
+
+        assert!(!board.is_check);
+        assert_eq!(board.result, GameResult::Draw(DrawReason::Stalemate));
+    }
+
+    #[test]
+    fn test_no_moves_accepted_after_game_over() {
+        let mut board = Board::default();
+        play(&mut board, (6, 5), (5, 5));
+        play(&mut board, (1, 4), (3, 4));
+        play(&mut board, (6, 6), (4, 6));
+        play(&mut board, (0, 3), (4, 7));
+
+        board.select_position(&Position { x: 6, y: 0 });
+
+        assert!(board.selected_position.is_none());
+        assert!(board.currently_legal_moves.is_none());
+    }
+
+    #[test]
+    fn test_threefold_repetition_is_draw() {
+        let mut board = Board::default();
+
+        for _ in 0..2 {
+            play(&mut board, (7, 6), (5, 5));
+            play(&mut board, (0, 6), (2, 5));
+            play(&mut board, (5, 5), (7, 6));
+            assert_eq!(board.result, GameResult::Ongoing);
+            play(&mut board, (2, 5), (0, 6));
+        }
+
+        assert_eq!(
+            board.result,
+            GameResult::Draw(DrawReason::ThreefoldRepetition)
+        );
+    }
+
+    #[test]
+    fn test_fifty_move_rule_is_draw() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 0, PieceType::King, PieceColor::White);
+        place(&mut board, 7, 7, PieceType::Rook, PieceColor::White);
+        place(&mut board, 0, 0, PieceType::King, PieceColor::Black);
+        board.halfmove_clock = 99;
+        play(&mut board, (7, 7), (6, 7));
+
+        assert_eq!(board.result, GameResult::Draw(DrawReason::FiftyMoveRule));
+    }
+
+    #[test]
+    fn test_halfmove_clock_resets_on_pawn_move() {
+        let mut board = Board::default();
+        play(&mut board, (7, 6), (5, 5));
+        play(&mut board, (0, 6), (2, 5));
+
+        assert_eq!(board.halfmove_clock, 2);
+
+        play(&mut board, (6, 4), (4, 4));
+
+        assert_eq!(board.halfmove_clock, 0);
+    }
+
+    #[test]
+    fn test_insufficient_material() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 0, PieceType::King, PieceColor::White);
+        place(&mut board, 0, 0, PieceType::King, PieceColor::Black);
+        assert!(board.is_insufficient_material());
+
+        place(&mut board, 4, 4, PieceType::Knight, PieceColor::White);
+        assert!(board.is_insufficient_material());
+
+        place(&mut board, 4, 4, PieceType::Bishop, PieceColor::White);
+        place(&mut board, 2, 2, PieceType::Bishop, PieceColor::Black);
+        assert!(board.is_insufficient_material());
+
+        place(&mut board, 2, 3, PieceType::Bishop, PieceColor::Black);
+        assert!(!board.is_insufficient_material());
+
+        board.board[2][3] = None;
+        place(&mut board, 6, 6, PieceType::Pawn, PieceColor::White);
+        assert!(!board.is_insufficient_material());
+    }
+
+    #[test]
+    fn test_capturing_last_piece_is_draw() {
+        let mut board = Board::init_empty();
+        place(&mut board, 7, 0, PieceType::King, PieceColor::White);
+        place(&mut board, 6, 1, PieceType::Rook, PieceColor::Black);
+        place(&mut board, 0, 7, PieceType::King, PieceColor::Black);
+        play(&mut board, (7, 0), (6, 1));
+
+        assert_eq!(
+            board.result,
+            GameResult::Draw(DrawReason::InsufficientMaterial)
+        );
+    }
+
+    #[test]
+    fn test_legal_moves_from_initial_position() {
+        let board = Board::default();
+
+        let legal_moves = board.legal_moves();
+
+        assert_eq!(legal_moves.len(), 20);
+        assert!(board.selected_position.is_none());
+        assert!(legal_moves.contains(&ChessMove::new(
+            Position { x: 6, y: 4 },
+            Position { x: 4, y: 4 },
+            MoveKind::DoublePawnPush,
+        )));
+    }
+
+    #[test]
+    fn test_moves_from_describes_move_kinds() {
+        let mut board = castling_board();
+        place(&mut board, 1, 1, PieceType::Pawn, PieceColor::White);
+        board.board[0][0] = None;
+
+        let king_moves = board.moves_from(&Position { x: 7, y: 4 });
+        assert!(king_moves.contains(&ChessMove::new(
+            Position { x: 7, y: 4 },
+            Position { x: 7, y: 6 },
+            MoveKind::Castling(CastlingSide::KingSide),
+        )));
+
+        let pawn_moves = board.moves_from(&Position { x: 1, y: 1 });
+        assert_eq!(pawn_moves.len(), 4);
+        for (chess_move, piece_type) in pawn_moves.iter().zip(PROMOTION_PIECES) {
+            assert_eq!(chess_move.promotion, Some(piece_type));
+            assert_eq!(chess_move.kind, MoveKind::Quiet);
+        }
+
+        assert!(board.moves_from(&Position { x: 0, y: 4 }).is_empty());
+    }
+
+    #[test]
+    fn test_play_move_rejects_illegal_moves() {
+        let mut board = Board::default();
+
+        let illegal_move = ChessMove::new(
+            Position { x: 6, y: 4 },
+            Position { x: 3, y: 4 },
+            MoveKind::Quiet,
+        );
+        assert!(!board.play_move(&illegal_move));
+        assert_eq!(board.on_turn, PieceColor::White);
+
+        let legal_move = ChessMove::new(
+            Position { x: 6, y: 4 },
+            Position { x: 4, y: 4 },
+            MoveKind::Quiet,
+        );
+        assert!(board.play_move(&legal_move));
+        assert_eq!(board.en_passant_target, Some(Position { x: 5, y: 4 }));
+        assert_eq!(board.on_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_chess_move_coordinate_notation() {
+        let mut chess_move = ChessMove::new(
+            Position { x: 1, y: 4 },
+            Position { x: 0, y: 4 },
+            MoveKind::Quiet,
+        );
+        chess_move.promotion = Some(PieceType::Queen);
+
+        assert_eq!(chess_move.to_string(), "e7e8q");
+        assert_eq!(
+            Position::from_algebraic("a1"),
+            Some(Position { x: 7, y: 0 })
+        );
+        assert_eq!(Position::from_algebraic("i9"), None);
+    }
+
+    #[test]
+    fn test_unmake_restores_exact_state() {
+        let mut board = Board::default();
+        let moves = [
+            ((6, 4), (4, 4)),
+            ((1, 0), (3, 0)),
+            ((4, 4), (3, 4)),
+            ((1, 3), (3, 3)),
+            ((3, 4), (2, 3)),
+            ((3, 0), (4, 0)),
+            ((2, 3), (1, 2)),
+            ((4, 0), (5, 0)),
+            ((7, 6), (5, 5)),
+            ((5, 0), (6, 1)),
+            ((7, 5), (6, 4)),
+            ((6, 1), (7, 0)),
+            ((7, 4), (7, 6)),
+        ];
+
+        let mut states = vec![format!("{:?}", board)];
+        for (from, to) in moves {
+            play(&mut board, from, to);
+            states.push(format!("{:?}", board));
+        }
+
+        assert_eq!(board.move_history().len(), moves.len());
+        assert_eq!(board.fullmove_number, 7);
+        assert_eq!(board.board[7][0].unwrap().piece_type, PieceType::Queen);
+        assert_eq!(board.board[7][5].unwrap().piece_type, PieceType::Rook);
+
+        states.pop();
+        while let Some(expected_state) = states.pop() {
+            assert!(board.unmake_move().is_some());
+            assert_eq!(format!("{:?}", board), expected_state);
+        }
+
+        assert!(board.unmake_move().is_none());
+    }
+
+    #[test]
+    fn test_unmake_reopens_finished_game() {
+        let mut board = Board::default();
+        play(&mut board, (6, 5), (5, 5));
+        play(&mut board, (1, 4), (3, 4));
+        play(&mut board, (6, 6), (4, 6));
+        play(&mut board, (0, 3), (4, 7));
+
+        assert!(board.result.is_over());
+
+        let unmade_move = board.unmake_move().unwrap();
+
+        assert_eq!(unmade_move.to, Position { x: 4, y: 7 });
+        assert_eq!(board.result, GameResult::Ongoing);
+        assert!(!board.is_check);
+        assert_eq!(board.on_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn test_zobrist_key_matches_recomputed_key() {
+        let mut board = Board::default();
+        // Covers en passant, a capture promotion and castling
+        let moves = [
+            ((6, 4), (4, 4)),
+            ((1, 0), (3, 0)),
+            ((4, 4), (3, 4)),
+            ((1, 3), (3, 3)),
+            ((3, 4), (2, 3)),
+            ((3, 0), (4, 0)),
+            ((2, 3), (1, 2)),
+            ((4, 0), (5, 0)),
+            ((7, 6), (5, 5)),
+            ((5, 0), (6, 1)),
+            ((7, 5), (6, 4)),
+            ((6, 1), (7, 0)),
+            ((7, 4), (7, 6)),
+        ];
+
+        let mut keys = vec![board.zobrist_key()];
+        for (from, to) in moves {
+            play(&mut board, from, to);
+            assert_eq!(board.zobrist_key(), board.compute_zobrist_key());
+            keys.push(board.zobrist_key());
+        }
+
+        keys.pop();
+        while let Some(expected_key) = keys.pop() {
+            board.unmake_move();
+            assert_eq!(board.zobrist_key(), expected_key);
+        }
+    }
+
+    #[test]
+    fn test_transpositions_share_zobrist_key() {
+        let mut first = Board::default();
+        play(&mut first, (7, 6), (5, 5));
+        play(&mut first, (0, 6), (2, 5));
+        play(&mut first, (7, 1), (5, 2));
+
+        let mut second = Board::default();
+        play(&mut second, (7, 1), (5, 2));
+        play(&mut second, (0, 6), (2, 5));
+        play(&mut second, (7, 6), (5, 5));
+
+        assert_eq!(first.zobrist_key(), second.zobrist_key());
+        assert_eq!(first, second);
+        assert_ne!(first.zobrist_key(), Board::default().zobrist_key());
+    }
+
+    #[test]
+    fn test_uncapturable_en_passant_target_is_not_hashed() {
+        let mut double_push = Board::default();
+        play(&mut double_push, (6, 4), (4, 4));
+
+        let mut board = Board::default();
+        board.board[6][4] = None;
+        board.board[4][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        board.on_turn = PieceColor::Black;
+        board.reset_history();
+
+        assert!(double_push.en_passant_target.is_some());
+        assert_eq!(double_push.zobrist_key(), board.zobrist_key());
+        assert_eq!(double_push, board);
+    }
+
+    #[test]
+    fn test_boards_in_hash_set() {
+        let mut board = Board::default();
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(board.clone());
+
+        play(&mut board, (7, 6), (5, 5));
+        play(&mut board, (0, 6), (2, 5));
+        assert!(seen.insert(board.clone()));
+
+        play(&mut board, (5, 5), (7, 6));
+        play(&mut board, (2, 5), (0, 6));
+        assert!(!seen.insert(board.clone()));
+        assert_eq!(seen.len(), 2);
+    }
+
+    #[test]
+    fn test_hash_follows_direct_edits() {
+        let mut double_push = Board::default();
+        play(&mut double_push, (6, 4), (4, 4));
+
+        let mut edited = Board::default();
+        edited.board[6][4] = None;
+        edited.board[4][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        edited.on_turn = PieceColor::Black;
+
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(double_push);
+        assert!(seen.contains(&edited));
+    }
+}
@@ -0,0 +1,110 @@
+use super::{PieceColor, Position};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CastlingSide {
+    KingSide,
+    QueenSide,
+}
+
+impl CastlingSide {
+    /// Column the rook starts from before castling.
+    pub fn rook_from_column(&self) -> usize {
+        match self {
+            CastlingSide::KingSide => 7,
+            CastlingSide::QueenSide => 0,
+        }
+    }
+
+    /// Column the rook lands on after castling.
+    pub fn rook_to_column(&self) -> usize {
+        match self {
+            CastlingSide::KingSide => 5,
+            CastlingSide::QueenSide => 3,
+        }
+    }
+
+    /// Column the king lands on after castling.
+    pub fn king_to_column(&self) -> usize {
+        match self {
+            CastlingSide::KingSide => 6,
+            CastlingSide::QueenSide => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool,
+}
+
+impl Default for CastlingRights {
+    fn default() -> Self {
+        Self {
+            white_king_side: true,
+            white_queen_side: true,
+            black_king_side: true,
+            black_queen_side: true,
+        }
+    }
+}
+
+impl CastlingRights {
+    pub fn none() -> Self {
+        Self {
+            white_king_side: false,
+            white_queen_side: false,
+            black_king_side: false,
+            black_queen_side: false,
+        }
+    }
+
+    pub fn can_castle(&self, color: PieceColor, side: CastlingSide) -> bool {
+        match (color, side) {
+            (PieceColor::White, CastlingSide::KingSide) => self.white_king_side,
+            (PieceColor::White, CastlingSide::QueenSide) => self.white_queen_side,
+            (PieceColor::Black, CastlingSide::KingSide) => self.black_king_side,
+            (PieceColor::Black, CastlingSide::QueenSide) => self.black_queen_side,
+        }
+    }
+
+    pub fn revoke(&mut self, color: PieceColor, side: CastlingSide) {
+        match (color, side) {
+            (PieceColor::White, CastlingSide::KingSide) => self.white_king_side = false,
+            (PieceColor::White, CastlingSide::QueenSide) => self.white_queen_side = false,
+            (PieceColor::Black, CastlingSide::KingSide) => self.black_king_side = false,
+            (PieceColor::Black, CastlingSide::QueenSide) => self.black_queen_side = false,
+        }
+    }
+
+    pub fn revoke_all(&mut self, color: PieceColor) {
+        self.revoke(color, CastlingSide::KingSide);
+        self.revoke(color, CastlingSide::QueenSide);
+    }
+
+    /// Revokes the right tied to a rook's starting square whenever a move
+    /// leaves from or lands on it, covering both rook moves and rook captures.
+    pub fn update_for_square(&mut self, position: &Position) {
+        let color = match position.x {
+            0 => PieceColor::Black,
+            7 => PieceColor::White,
+            _ => return,
+        };
+
+        match position.y {
+            0 => self.revoke(color, CastlingSide::QueenSide),
+            7 => self.revoke(color, CastlingSide::KingSide),
+            _ => {}
+        }
+    }
+}
+
+/// Row holding the pieces of the given color at the start of the game.
+pub fn home_row(color: PieceColor) -> usize {
+    match color {
+        PieceColor::White => 7,
+        PieceColor::Black => 0,
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use super::{CastlingRights, CastlingSide, GameResult, Piece, PieceType, Position};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveKind {
+    Quiet,
+    Capture,
+    DoublePawnPush,
+    EnPassant,
+    Castling(CastlingSide),
+}
+
+/// A fully described move, independent of any cursor or selection state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChessMove {
+    pub from: Position,
+    pub to: Position,
+    pub promotion: Option<PieceType>,
+    pub kind: MoveKind,
+}
+
+impl ChessMove {
+    pub fn new(from: Position, to: Position, kind: MoveKind) -> Self {
+        Self {
+            from,
+            to,
+            promotion: None,
+            kind,
+        }
+    }
+
+    pub fn is_capture(&self) -> bool {
+        matches!(self.kind, MoveKind::Capture | MoveKind::EnPassant)
+    }
+}
+
+/// Coordinate notation as used by UCI, e.g. `e2e4` or `e7e8q`.
+impl Display for ChessMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.from, self.to)?;
+        if let Some(piece_type) = self.promotion {
+            write!(f, "{}", piece_type.to_string().to_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+/// A played move together with the state it replaced, so it can be unmade exactly.
+#[derive(Debug, Clone)]
+pub struct UndoInfo {
+    pub chess_move: ChessMove,
+    pub moved_piece: Piece,
+    pub captured_piece: Option<Piece>,
+    pub castling_rights: CastlingRights,
+    pub en_passant_target: Option<Position>,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    pub is_check: bool,
+    pub result: GameResult,
+    pub zobrist_key: u64,
+}
@@ -0,0 +1,17 @@
+use super::{ChessMove, PieceColor};
+
+/// Common interface of the position representations, so search and perft
+/// code can run on either the `Board` or the faster `BitboardPosition`.
+pub trait ChessPosition: Clone {
+    fn side_to_move(&self) -> PieceColor;
+
+    fn legal_moves(&self) -> Vec<ChessMove>;
+
+    /// Applies a move taken from `legal_moves` without validating it again.
+    fn make_move(&mut self, chess_move: &ChessMove);
+
+    fn is_in_check(&self) -> bool;
+
+    /// Hash of the position, equal for positions that count as repetitions.
+    fn zobrist_key(&self) -> u64;
+}
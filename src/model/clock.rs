@@ -0,0 +1,400 @@
+use std::{error::Error, fmt::Display, str::FromStr, time::Duration};
+
+use super::{Board, DrawReason, GameResult, PieceColor, PieceType, WinReason};
+
+/// Extra time each player gets for their moves.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Bonus {
+    #[default]
+    None,
+    /// Fischer increment, added after every move.
+    Increment(Duration),
+    /// Bronstein delay: the time used on a move is given back, up to this much.
+    Bronstein(Duration),
+    /// Simple delay: the clock only starts counting down after this much time.
+    Delay(Duration),
+}
+
+/// Time for a number of moves, or for the rest of the game when `moves` is `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Period {
+    pub moves: Option<u32>,
+    pub time: Duration,
+}
+
+/// Periods played one after the other, the last one repeating if it has a move count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeControl {
+    pub periods: Vec<Period>,
+    pub bonus: Bonus,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeControlError(pub String);
+
+impl Display for TimeControlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid time control: {}", self.0)
+    }
+}
+
+impl Error for TimeControlError {}
+
+/// Reads a number of `unit`s, e.g. minutes or seconds.
+fn parse_duration(text: &str, unit: Duration, what: &str) -> Result<Duration, TimeControlError> {
+    text.parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * unit.as_secs_f64()).ok())
+        .ok_or_else(|| TimeControlError(format!("'{}' is not a valid {}", text, what)))
+}
+
+fn parse_period(text: &str) -> Result<Period, TimeControlError> {
+    let (moves, minutes) = match text.split_once('/') {
+        Some((moves, minutes)) => {
+            let moves = moves
+                .parse::<u32>()
+                .ok()
+                .filter(|moves| *moves > 0)
+                .ok_or_else(|| TimeControlError(format!("'{}' is not a move count", moves)))?;
+            (Some(moves), minutes)
+        }
+        None => (None, text),
+    };
+
+    let time = parse_duration(minutes, Duration::from_secs(60), "number of minutes")?;
+    if time.is_zero() {
+        return Err(TimeControlError(String::from("periods need some time")));
+    }
+
+    Ok(Period { moves, time })
+}
+
+/// Reads `[moves/]minutes[:[moves/]minutes...][+|d|b seconds]`, for example `5+3`
+/// (Fischer increment), `5d2` (simple delay), `5b2` (Bronstein) or `40/90:30+30`.
+impl FromStr for TimeControl {
+    type Err = TimeControlError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (periods, bonus) = match spec.find(['+', 'd', 'b']) {
+            Some(index) => {
+                let seconds = &spec[index + 1..];
+                let bonus = parse_duration(seconds, Duration::from_secs(1), "number of seconds")?;
+                let bonus = match &spec[index..=index] {
+                    "+" => Bonus::Increment(bonus),
+                    "d" => Bonus::Delay(bonus),
+                    _ => Bonus::Bronstein(bonus),
+                };
+                (&spec[..index], bonus)
+            }
+            None => (spec, Bonus::None),
+        };
+
+        let periods = periods
+            .split(':')
+            .map(parse_period)
+            .collect::<Result<Vec<Period>, TimeControlError>>()?;
+        if periods[..periods.len() - 1]
+            .iter()
+            .any(|period| period.moves.is_none())
+        {
+            return Err(TimeControlError(String::from(
+                "only the last period can be for the rest of the game",
+            )));
+        }
+
+        Ok(Self { periods, bonus })
+    }
+}
+
+impl Display for TimeControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let periods: Vec<String> = self
+            .periods
+            .iter()
+            .map(|period| {
+                let minutes = period.time.as_secs_f64() / 60.0;
+                match period.moves {
+                    Some(moves) => format!("{}/{}", moves, minutes),
+                    None => minutes.to_string(),
+                }
+            })
+            .collect();
+        write!(f, "{}", periods.join(":"))?;
+
+        match self.bonus {
+            Bonus::None => Ok(()),
+            Bonus::Increment(time) => write!(f, "+{}", time.as_secs_f64()),
+            Bonus::Delay(time) => write!(f, "d{}", time.as_secs_f64()),
+            Bonus::Bronstein(time) => write!(f, "b{}", time.as_secs_f64()),
+        }
+    }
+}
+
+/// One player's side of the clock.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerClock {
+    pub remaining: Duration,
+    period: usize,
+    period_moves: u32,
+    /// Time spent on the move in progress.
+    turn_time: Duration,
+}
+
+/// Clocks for both players. Neither runs before the first move is made.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChessClock {
+    pub time_control: TimeControl,
+    pub white: PlayerClock,
+    pub black: PlayerClock,
+    pub running: Option<PieceColor>,
+    pub flagged: Option<PieceColor>,
+}
+
+impl ChessClock {
+    pub fn new(time_control: TimeControl) -> Self {
+        let player = PlayerClock {
+            remaining: time_control.periods[0].time,
+            period: 0,
+            period_moves: 0,
+            turn_time: Duration::ZERO,
+        };
+
+        Self {
+            time_control,
+            white: player.clone(),
+            black: player,
+            running: None,
+            flagged: None,
+        }
+    }
+
+    pub fn player(&self, color: PieceColor) -> &PlayerClock {
+        match color {
+            PieceColor::White => &self.white,
+            PieceColor::Black => &self.black,
+        }
+    }
+
+    fn player_mut(&mut self, color: PieceColor) -> &mut PlayerClock {
+        match color {
+            PieceColor::White => &mut self.white,
+            PieceColor::Black => &mut self.black,
+        }
+    }
+
+    /// Counts `elapsed` against the running side and returns it if its flag fell.
+    pub fn tick(&mut self, elapsed: Duration) -> Option<PieceColor> {
+        let color = self.running?;
+        let delay = match self.time_control.bonus {
+            Bonus::Delay(delay) => delay,
+            _ => Duration::ZERO,
+        };
+
+        let player = self.player_mut(color);
+        let counted_before = player.turn_time.saturating_sub(delay);
+        player.turn_time += elapsed;
+        let counted = player.turn_time.saturating_sub(delay) - counted_before;
+        player.remaining = player.remaining.saturating_sub(counted);
+
+        if !player.remaining.is_zero() {
+            return None;
+        }
+        self.running = None;
+        self.flagged = Some(color);
+        Some(color)
+    }
+
+    /// Ends the turn of `color`: adds its bonus and the time of a new period when one
+    /// starts, then starts the opponent's clock.
+    pub fn press(&mut self, color: PieceColor) {
+        if self.flagged.is_some() {
+            return;
+        }
+
+        let TimeControl { periods, bonus } = self.time_control.clone();
+        let player = self.player_mut(color);
+        match bonus {
+            Bonus::Increment(increment) => player.remaining += increment,
+            Bonus::Bronstein(delay) => player.remaining += player.turn_time.min(delay),
+            Bonus::Delay(_) | Bonus::None => {}
+        }
+        player.turn_time = Duration::ZERO;
+
+        player.period_moves += 1;
+        if periods[player.period].moves == Some(player.period_moves) {
+            player.period_moves = 0;
+            player.period = (player.period + 1).min(periods.len() - 1);
+            player.remaining += periods[player.period].time;
+        }
+
+        self.running = Some(color.opposite());
+    }
+
+    /// Under a tenth of the first period, and at most half a minute.
+    pub fn is_low(&self, color: PieceColor) -> bool {
+        let threshold = (self.time_control.periods[0].time / 10).min(Duration::from_secs(30));
+        self.player(color).remaining < threshold
+    }
+}
+
+/// Clock display: `h:mm:ss` from an hour, `m:ss` below that and tenths under 10 seconds.
+pub fn format_clock(time: Duration) -> String {
+    let seconds = time.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else if seconds >= 10 {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    } else {
+        format!("0:0{}.{}", seconds, time.subsec_millis() / 100)
+    }
+}
+
+impl Board {
+    /// Whether `color` has anything that could still give mate besides its king.
+    pub fn has_mating_material(&self, color: PieceColor) -> bool {
+        let pieces: Vec<PieceType> = self
+            .board
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|piece| piece.piece_color == color && piece.piece_type != PieceType::King)
+            .map(|piece| piece.piece_type)
+            .collect();
+
+        !matches!(
+            pieces.as_slice(),
+            [] | [PieceType::Knight] | [PieceType::Bishop]
+        )
+    }
+
+    /// Ends the game after `color` ran out of time. It is drawn when the opponent
+    /// could not have won anyway.
+    pub fn lose_on_time(&mut self, color: PieceColor) {
+        let winner = color.opposite();
+        self.result = if self.has_mating_material(winner) {
+            GameResult::win_for(winner, WinReason::Timeout)
+        } else {
+            GameResult::Draw(DrawReason::TimeoutVsInsufficientMaterial)
+        };
+        self.selected_position = None;
+        self.currently_legal_moves = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seconds(seconds: u64) -> Duration {
+        Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn test_parse_time_controls() {
+        let blitz: TimeControl = "3+2".parse().unwrap();
+        assert_eq!(
+            blitz,
+            TimeControl {
+                periods: vec![Period {
+                    moves: None,
+                    time: seconds(180),
+                }],
+                bonus: Bonus::Increment(seconds(2)),
+            }
+        );
+
+        let classical: TimeControl = "40/90:30+30".parse().unwrap();
+        assert_eq!(classical.periods.len(), 2);
+        assert_eq!(classical.periods[0].moves, Some(40));
+        assert_eq!(classical.to_string(), "40/90:30+30");
+
+        assert_eq!(
+            "5d3".parse::<TimeControl>().unwrap().bonus,
+            Bonus::Delay(seconds(3))
+        );
+        assert_eq!(
+            "5b3".parse::<TimeControl>().unwrap().bonus,
+            Bonus::Bronstein(seconds(3))
+        );
+        assert!("".parse::<TimeControl>().is_err());
+        assert!("0+3".parse::<TimeControl>().is_err());
+        assert!("90:40/30".parse::<TimeControl>().is_err());
+        assert!("5+x".parse::<TimeControl>().is_err());
+    }
+
+    #[test]
+    fn test_increment_and_flag_fall() {
+        let mut clock = ChessClock::new("1+2".parse().unwrap());
+        assert_eq!(clock.tick(seconds(5)), None);
+        assert_eq!(clock.white.remaining, seconds(60));
+
+        clock.press(PieceColor::White);
+        assert_eq!(clock.white.remaining, seconds(62));
+        assert_eq!(clock.running, Some(PieceColor::Black));
+
+        assert_eq!(clock.tick(seconds(59)), None);
+        assert!(clock.is_low(PieceColor::Black));
+        assert_eq!(clock.tick(seconds(1)), Some(PieceColor::Black));
+        assert_eq!(clock.flagged, Some(PieceColor::Black));
+        assert_eq!(clock.running, None);
+    }
+
+    #[test]
+    fn test_delays() {
+        let mut simple = ChessClock::new("1d5".parse().unwrap());
+        simple.press(PieceColor::White);
+        simple.tick(seconds(3));
+        simple.tick(seconds(4));
+        assert_eq!(simple.black.remaining, seconds(58));
+
+        let mut bronstein = ChessClock::new("1b5".parse().unwrap());
+        bronstein.press(PieceColor::White);
+        bronstein.tick(seconds(3));
+        bronstein.press(PieceColor::Black);
+        assert_eq!(bronstein.black.remaining, seconds(60));
+        bronstein.tick(seconds(8));
+        bronstein.press(PieceColor::White);
+        assert_eq!(bronstein.white.remaining, seconds(57));
+    }
+
+    #[test]
+    fn test_periods() {
+        let mut clock = ChessClock::new("2/1:1/2".parse().unwrap());
+        clock.press(PieceColor::White);
+        assert_eq!(clock.white.remaining, seconds(60));
+        clock.press(PieceColor::Black);
+        clock.press(PieceColor::White);
+        assert_eq!(clock.white.remaining, seconds(180));
+
+        // The last period repeats for every further move
+        clock.press(PieceColor::Black);
+        clock.press(PieceColor::White);
+        assert_eq!(clock.white.remaining, seconds(300));
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(seconds(5400)), "1:30:00");
+        assert_eq!(format_clock(seconds(65)), "1:05");
+        assert_eq!(format_clock(Duration::from_millis(9_870)), "0:09.8");
+    }
+
+    #[test]
+    fn test_lose_on_time() {
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 1").unwrap();
+        board.lose_on_time(PieceColor::Black);
+        assert_eq!(board.result, GameResult::WhiteWins(WinReason::Timeout));
+
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+        board.lose_on_time(PieceColor::White);
+        assert_eq!(
+            board.result,
+            GameResult::Draw(DrawReason::TimeoutVsInsufficientMaterial)
+        );
+    }
+}
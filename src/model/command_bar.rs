@@ -0,0 +1,103 @@
+use super::{san::SanError, text_input::TextInput, Board, ChessMove};
+
+/// Move typed on the game screen, with live validation and tab completion.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandBarState {
+    pub input: TextInput,
+    /// Result of resolving the current input, `None` while it is empty.
+    pub validation: Option<Result<String, SanError>>,
+    /// Candidates offered by the last Tab press and the one currently filled in.
+    pub completions: Vec<String>,
+    pub completion_index: Option<usize>,
+}
+
+impl CommandBarState {
+    /// Re-validates the input after an edit, which also ends any completion cycle.
+    pub fn update(&mut self, board: &Board) {
+        self.completions.clear();
+        self.completion_index = None;
+        self.validate(board);
+    }
+
+    fn validate(&mut self, board: &Board) {
+        let input = self.input.value.trim();
+        self.validation = if input.is_empty() {
+            None
+        } else {
+            Some(
+                board
+                    .parse_move(input)
+                    .map(|chess_move| board.san(&chess_move)),
+            )
+        };
+    }
+
+    /// Fills in the next legal move that starts with what was typed before the first Tab.
+    pub fn complete(&mut self, board: &Board) {
+        if self.completion_index.is_none() {
+            self.completions = board.move_completions(self.input.value.trim());
+        }
+        if self.completions.is_empty() {
+            return;
+        }
+
+        let index = self
+            .completion_index
+            .map_or(0, |index| (index + 1) % self.completions.len());
+        self.completion_index = Some(index);
+        self.input = TextInput::new(&self.completions[index]);
+        self.validate(board);
+    }
+
+    pub fn chess_move(&self, board: &Board) -> Result<ChessMove, SanError> {
+        board.parse_move(&self.input.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_follows_input() {
+        let board = Board::default();
+        let mut command_bar = CommandBarState::default();
+
+        command_bar.input.insert('N');
+        command_bar.update(&board);
+        assert!(matches!(
+            command_bar.validation,
+            Some(Err(SanError::Malformed(_)))
+        ));
+
+        command_bar.input.insert_str("f3");
+        command_bar.update(&board);
+        assert_eq!(command_bar.validation, Some(Ok(String::from("Nf3"))));
+
+        command_bar.input.clear();
+        command_bar.update(&board);
+        assert_eq!(command_bar.validation, None);
+    }
+
+    #[test]
+    fn test_tab_cycles_through_completions() {
+        let board = Board::default();
+        let mut command_bar = CommandBarState::default();
+        command_bar.input.insert_str("N");
+        command_bar.update(&board);
+
+        command_bar.complete(&board);
+        assert_eq!(command_bar.input.value, "Na3");
+        command_bar.complete(&board);
+        assert_eq!(command_bar.input.value, "Nc3");
+        assert_eq!(command_bar.validation, Some(Ok(String::from("Nc3"))));
+
+        (0..3).for_each(|_| command_bar.complete(&board));
+        assert_eq!(command_bar.input.value, "Na3");
+
+        command_bar.input.delete_previous();
+        command_bar.update(&board);
+        command_bar.complete(&board);
+        assert_eq!(command_bar.completions, ["Na3"]);
+    }
+}
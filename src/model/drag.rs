@@ -0,0 +1,10 @@
+use super::{Piece, Position};
+
+/// A piece being dragged with the mouse, and the terminal cell under the pointer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragState {
+    pub from: Position,
+    pub piece: Piece,
+    pub column: u16,
+    pub row: u16,
+}
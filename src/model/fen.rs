@@ -0,0 +1,509 @@
+use std::{error::Error, fmt::Display};
+
+use super::{
+    home_row, Board, CastlingRights, CastlingSide, Piece, PieceColor, PieceType, Position,
+};
+
+pub const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FenError {
+    WrongFieldCount(usize),
+    WrongRankCount(usize),
+    InvalidPiece(char),
+    WrongRankLength { rank: usize, length: usize },
+    ConsecutiveEmptySquares { rank: usize },
+    WrongKingCount { color: PieceColor, count: usize },
+    PawnOnBackRank(Position),
+    InvalidSideToMove(String),
+    InvalidCastlingRights(String),
+    CastlingRightWithoutPieces(char),
+    InvalidEnPassantTarget(String),
+    ImpossibleEnPassantTarget(Position),
+    InvalidHalfmoveClock(String),
+    InvalidFullmoveNumber(String),
+    OpponentInCheck,
+}
+
+impl Display for FenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FenError::WrongFieldCount(count) => {
+                write!(f, "expected 6 space separated fields, found {}", count)
+            }
+            FenError::WrongRankCount(count) => {
+                write!(f, "expected 8 ranks separated by '/', found {}", count)
+            }
+            FenError::InvalidPiece(c) => write!(f, "'{}' is not a piece letter", c),
+            FenError::WrongRankLength { rank, length } => {
+                write!(f, "rank {} describes {} squares instead of 8", rank, length)
+            }
+            FenError::ConsecutiveEmptySquares { rank } => {
+                write!(f, "rank {} has two empty square counts in a row", rank)
+            }
+            FenError::WrongKingCount { color, count } => {
+                write!(f, "{:?} must have exactly one king, found {}", color, count)
+            }
+            FenError::PawnOnBackRank(position) => {
+                write!(
+                    f,
+                    "pawn on {} cannot stand on the first or last rank",
+                    position
+                )
+            }
+            FenError::InvalidSideToMove(field) => {
+                write!(f, "side to move must be 'w' or 'b', found '{}'", field)
+            }
+            FenError::InvalidCastlingRights(field) => {
+                write!(
+                    f,
+                    "castling rights must be '-' or a subset of 'KQkq', found '{}'",
+                    field
+                )
+            }
+            FenError::CastlingRightWithoutPieces(right) => {
+                write!(
+                    f,
+                    "castling right '{}' needs the king and rook on their starting squares",
+                    right
+                )
+            }
+            FenError::InvalidEnPassantTarget(field) => {
+                write!(
+                    f,
+                    "en passant target must be '-' or a square, found '{}'",
+                    field
+                )
+            }
+            FenError::ImpossibleEnPassantTarget(position) => {
+                write!(
+                    f,
+                    "en passant target {} does not follow a double pawn move",
+                    position
+                )
+            }
+            FenError::InvalidHalfmoveClock(field) => {
+                write!(f, "halfmove clock must be a number, found '{}'", field)
+            }
+            FenError::InvalidFullmoveNumber(field) => {
+                write!(
+                    f,
+                    "fullmove number must be a positive number, found '{}'",
+                    field
+                )
+            }
+            FenError::OpponentInCheck => {
+                write!(f, "the side that just moved cannot be left in check")
+            }
+        }
+    }
+}
+
+impl Error for FenError {}
+
+fn parse_piece(c: char) -> Result<Piece, FenError> {
+    let piece_type = match c.to_ascii_lowercase() {
+        'k' => PieceType::King,
+        'q' => PieceType::Queen,
+        'r' => PieceType::Rook,
+        'b' => PieceType::Bishop,
+        'n' => PieceType::Knight,
+        'p' => PieceType::Pawn,
+        _ => return Err(FenError::InvalidPiece(c)),
+    };
+    let piece_color = if c.is_ascii_uppercase() {
+        PieceColor::White
+    } else {
+        PieceColor::Black
+    };
+
+    Ok(Piece::new(piece_type, piece_color))
+}
+
+fn parse_placement(field: &str) -> Result<[[Option<Piece>; 8]; 8], FenError> {
+    let ranks: Vec<&str> = field.split('/').collect();
+    if ranks.len() != 8 {
+        return Err(FenError::WrongRankCount(ranks.len()));
+    }
+
+    let mut board = [[None; 8]; 8];
+
+    for (x, rank) in ranks.iter().enumerate() {
+        let mut y = 0;
+        let mut previous_was_digit = false;
+        for c in rank.chars() {
+            match c.to_digit(10) {
+                Some(_) if previous_was_digit => {
+                    return Err(FenError::ConsecutiveEmptySquares { rank: 8 - x });
+                }
+                Some(empty_squares @ 1..=8) => {
+                    y += empty_squares as usize;
+                    previous_was_digit = true;
+                }
+                _ => {
+                    previous_was_digit = false;
+                    let piece = parse_piece(c)?;
+                    if y < 8 {
+                        board[x][y] = Some(piece);
+                    }
+                    y += 1;
+                }
+            }
+        }
+
+        if y != 8 {
+            return Err(FenError::WrongRankLength {
+                rank: 8 - x,
+                length: y,
+            });
+        }
+    }
+
+    Ok(board)
+}
+
+fn parse_castling_rights(field: &str) -> Result<CastlingRights, FenError> {
+    let mut castling_rights = CastlingRights::none();
+    if field == "-" {
+        return Ok(castling_rights);
+    }
+
+    for c in field.chars() {
+        let right = match c {
+            'K' => &mut castling_rights.white_king_side,
+            'Q' => &mut castling_rights.white_queen_side,
+            'k' => &mut castling_rights.black_king_side,
+            'q' => &mut castling_rights.black_queen_side,
+            _ => return Err(FenError::InvalidCastlingRights(field.to_string())),
+        };
+        if *right {
+            return Err(FenError::InvalidCastlingRights(field.to_string()));
+        }
+        *right = true;
+    }
+
+    Ok(castling_rights)
+}
+
+fn validate_pieces(board: &Board) -> Result<(), FenError> {
+    for color in [PieceColor::White, PieceColor::Black] {
+        let count = board
+            .board
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|piece| piece.piece_type == PieceType::King && piece.piece_color == color)
+            .count();
+        if count != 1 {
+            return Err(FenError::WrongKingCount { color, count });
+        }
+    }
+
+    for x in [0, 7] {
+        if let Some(y) = (0..8).find(
+            |&y| matches!(board.board[x][y], Some(piece) if piece.piece_type == PieceType::Pawn),
+        ) {
+            return Err(FenError::PawnOnBackRank(Position { x, y }));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_castling_rights(board: &Board) -> Result<(), FenError> {
+    let rights = [
+        ('K', PieceColor::White, CastlingSide::KingSide),
+        ('Q', PieceColor::White, CastlingSide::QueenSide),
+        ('k', PieceColor::Black, CastlingSide::KingSide),
+        ('q', PieceColor::Black, CastlingSide::QueenSide),
+    ];
+
+    for (right, color, side) in rights {
+        if !board.castling_rights.can_castle(color, side) {
+            continue;
+        }
+
+        let row = home_row(color);
+        let has_piece = |y: usize, piece_type: PieceType| {
+            matches!(board.board[row][y], Some(piece)
+                if piece.piece_type == piece_type && piece.piece_color == color)
+        };
+        if !has_piece(4, PieceType::King) || !has_piece(side.rook_from_column(), PieceType::Rook) {
+            return Err(FenError::CastlingRightWithoutPieces(right));
+        }
+    }
+
+    Ok(())
+}
+
+/// The target must sit right behind a pawn of the side that just moved, with the
+/// square it came from still empty.
+fn validate_en_passant_target(board: &Board) -> Result<(), FenError> {
+    let Some(target) = &board.en_passant_target else {
+        return Ok(());
+    };
+
+    let (target_row, pawn_row, origin_row) = match board.on_turn {
+        PieceColor::White => (2, 3, 1),
+        PieceColor::Black => (5, 4, 6),
+    };
+    let pawn_in_front = matches!(board.board[pawn_row][target.y], Some(piece)
+        if piece.piece_type == PieceType::Pawn && piece.piece_color != board.on_turn);
+
+    if target.x != target_row
+        || !pawn_in_front
+        || board.board[target.x][target.y].is_some()
+        || board.board[origin_row][target.y].is_some()
+    {
+        return Err(FenError::ImpossibleEnPassantTarget(target.clone()));
+    }
+
+    Ok(())
+}
+
+impl Board {
+    /// Builds a board from a position in Forsyth-Edwards Notation.
+    pub fn from_fen(fen: &str) -> Result<Self, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 6 {
+            return Err(FenError::WrongFieldCount(fields.len()));
+        }
+
+        let mut board = Board::default();
+
+        board.board = parse_placement(fields[0])?;
+
+        board.on_turn = match fields[1] {
+            "w" => PieceColor::White,
+            "b" => PieceColor::Black,
+            field => return Err(FenError::InvalidSideToMove(field.to_string())),
+        };
+
+        board.castling_rights = parse_castling_rights(fields[2])?;
+
+        board.en_passant_target = match fields[3] {
+            "-" => None,
+            field => match Position::from_algebraic(field) {
+                Some(position) => Some(position),
+                None => return Err(FenError::InvalidEnPassantTarget(field.to_string())),
+            },
+        };
+
+        board.halfmove_clock = fields[4]
+            .parse()
+            .map_err(|_| FenError::InvalidHalfmoveClock(fields[4].to_string()))?;
+
+        board.fullmove_number = match fields[5].parse() {
+            Ok(number) if number > 0 => number,
+            _ => return Err(FenError::InvalidFullmoveNumber(fields[5].to_string())),
+        };
+
+        validate_pieces(&board)?;
+        validate_castling_rights(&board)?;
+        validate_en_passant_target(&board)?;
+        if board.is_king_attacked(board.on_turn.opposite()) {
+            return Err(FenError::OpponentInCheck);
+        }
+
+        board.reset_history();
+
+        Ok(board)
+    }
+
+    /// Describes the position in Forsyth-Edwards Notation.
+    pub fn to_fen(&self) -> String {
+        let placement = self
+            .board
+            .iter()
+            .map(|row| {
+                let mut rank = String::new();
+                let mut empty_squares = 0;
+                for cell in row {
+                    match cell {
+                        Some(piece) => {
+                            if empty_squares > 0 {
+                                rank.push_str(&empty_squares.to_string());
+                                empty_squares = 0;
+                            }
+                            rank.push(piece.fen_char());
+                        }
+                        None => empty_squares += 1,
+                    }
+                }
+                if empty_squares > 0 {
+                    rank.push_str(&empty_squares.to_string());
+                }
+                rank
+            })
+            .collect::<Vec<String>>()
+            .join("/");
+
+        let side_to_move = match self.on_turn {
+            PieceColor::White => "w",
+            PieceColor::Black => "b",
+        };
+
+        let castling_rights: String = [
+            (self.castling_rights.white_king_side, 'K'),
+            (self.castling_rights.white_queen_side, 'Q'),
+            (self.castling_rights.black_king_side, 'k'),
+            (self.castling_rights.black_queen_side, 'q'),
+        ]
+        .iter()
+        .filter(|(has_right, _)| *has_right)
+        .map(|(_, c)| *c)
+        .collect();
+
+        let en_passant_target = self
+            .en_passant_target
+            .as_ref()
+            .map_or(String::from("-"), Position::to_string);
+
+        format!(
+            "{} {} {} {} {} {}",
+            placement,
+            side_to_move,
+            if castling_rights.is_empty() {
+                "-"
+            } else {
+                &castling_rights
+            },
+            en_passant_target,
+            self.halfmove_clock,
+            self.fullmove_number
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_fen_round_trip() {
+        let board = Board::from_fen(STARTING_FEN).unwrap();
+
+        assert_eq!(board, Board::default());
+        assert_eq!(board.to_fen(), STARTING_FEN);
+        assert_eq!(Board::default().to_fen(), STARTING_FEN);
+    }
+
+    #[test]
+    fn test_fen_round_trip() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 b - - 12 10",
+        ];
+
+        for fen in fens {
+            assert_eq!(Board::from_fen(fen).unwrap().to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn test_fen_export_after_moves() {
+        let mut board = Board::default();
+        let moves = board.legal_moves();
+        let double_push = moves
+            .iter()
+            .find(|chess_move| chess_move.to_string() == "e2e4")
+            .unwrap();
+        board.play_move(double_push);
+
+        assert_eq!(
+            board.to_fen(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+    }
+
+    #[test]
+    fn test_malformed_fields_are_rejected() {
+        let cases = [
+            ("8/8/8/8/8/8/8/K6k w - -", FenError::WrongFieldCount(4)),
+            ("8/8/8/8/8/8/K6k w - - 0 1", FenError::WrongRankCount(7)),
+            ("8/8/8/8/8/8/8/K5xk w - - 0 1", FenError::InvalidPiece('x')),
+            (
+                "8/8/8/8/8/8/8/K5k w - - 0 1",
+                FenError::WrongRankLength { rank: 1, length: 7 },
+            ),
+            (
+                "8/8/8/8/8/8/8/K33k w - - 0 1",
+                FenError::ConsecutiveEmptySquares { rank: 1 },
+            ),
+            (
+                "8/8/8/8/8/8/8/K6k x - - 0 1",
+                FenError::InvalidSideToMove(String::from("x")),
+            ),
+            (
+                "8/8/8/8/8/8/8/K6k w KK - 0 1",
+                FenError::InvalidCastlingRights(String::from("KK")),
+            ),
+            (
+                "8/8/8/8/8/8/8/K6k w - e9 0 1",
+                FenError::InvalidEnPassantTarget(String::from("e9")),
+            ),
+            (
+                "8/8/8/8/8/8/8/K6k w - - x 1",
+                FenError::InvalidHalfmoveClock(String::from("x")),
+            ),
+            (
+                "8/8/8/8/8/8/8/K6k w - - 0 0",
+                FenError::InvalidFullmoveNumber(String::from("0")),
+            ),
+        ];
+
+        for (fen, error) in cases {
+            assert_eq!(Board::from_fen(fen).unwrap_err(), error, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_impossible_positions_are_rejected() {
+        let cases = [
+            (
+                "8/8/8/8/8/8/8/K7 w - - 0 1",
+                FenError::WrongKingCount {
+                    color: PieceColor::Black,
+                    count: 0,
+                },
+            ),
+            (
+                "k7/8/8/8/8/8/8/KK6 w - - 0 1",
+                FenError::WrongKingCount {
+                    color: PieceColor::White,
+                    count: 2,
+                },
+            ),
+            (
+                "k6P/8/8/8/8/8/8/K7 w - - 0 1",
+                FenError::PawnOnBackRank(Position { x: 0, y: 7 }),
+            ),
+            (
+                "k7/8/8/8/8/8/8/K6R w K - 0 1",
+                FenError::CastlingRightWithoutPieces('K'),
+            ),
+            (
+                "k7/8/8/8/8/8/8/K7 w - e6 0 1",
+                FenError::ImpossibleEnPassantTarget(Position { x: 2, y: 4 }),
+            ),
+            (
+                "k7/8/8/8/4P3/8/8/K7 w - e3 0 1",
+                FenError::ImpossibleEnPassantTarget(Position { x: 5, y: 4 }),
+            ),
+            ("k6R/8/8/8/8/8/8/K7 w - - 0 1", FenError::OpponentInCheck),
+        ];
+
+        for (fen, error) in cases {
+            assert_eq!(Board::from_fen(fen).unwrap_err(), error, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_errors_are_descriptive() {
+        let error = Board::from_fen("8/8/8/8/8/8/8/K5k w - - 0 1").unwrap_err();
+
+        assert_eq!(error.to_string(), "rank 1 describes 7 squares instead of 8");
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+use super::PieceColor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WinReason {
+    Checkmate,
+    Timeout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawReason {
+    Stalemate,
+    FiftyMoveRule,
+    ThreefoldRepetition,
+    InsufficientMaterial,
+    /// A flag fell but the opponent had no way to give mate.
+    TimeoutVsInsufficientMaterial,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameResult {
+    Ongoing,
+    WhiteWins(WinReason),
+    BlackWins(WinReason),
+    Draw(DrawReason),
+}
+
+impl GameResult {
+    pub fn win_for(color: PieceColor, reason: WinReason) -> Self {
+        match color {
+            PieceColor::White => GameResult::WhiteWins(reason),
+            PieceColor::Black => GameResult::BlackWins(reason),
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        *self != GameResult::Ongoing
+    }
+
+    /// Result as written in PGN tags and movetext.
+    pub fn pgn_token(&self) -> &'static str {
+        match self {
+            GameResult::Ongoing => "*",
+            GameResult::WhiteWins(_) => "1-0",
+            GameResult::BlackWins(_) => "0-1",
+            GameResult::Draw(_) => "1/2-1/2",
+        }
+    }
+}
+
+impl Display for WinReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WinReason::Checkmate => write!(f, "checkmate"),
+            WinReason::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+impl Display for DrawReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DrawReason::Stalemate => write!(f, "stalemate"),
+            DrawReason::FiftyMoveRule => write!(f, "the fifty-move rule"),
+            DrawReason::ThreefoldRepetition => write!(f, "threefold repetition"),
+            DrawReason::InsufficientMaterial => write!(f, "insufficient material"),
+            DrawReason::TimeoutVsInsufficientMaterial => {
+                write!(f, "timeout vs insufficient material")
+            }
+        }
+    }
+}
+
+impl Display for GameResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameResult::Ongoing => write!(f, "Ongoing"),
+            GameResult::WhiteWins(reason) => write!(f, "White wins by {}", reason),
+            GameResult::BlackWins(reason) => write!(f, "Black wins by {}", reason),
+            GameResult::Draw(reason) => write!(f, "Draw by {}", reason),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::{Board, Piece, PieceColor, PieceType};
+
+/// Order in which captured pieces are listed, most valuable first.
+const CAPTURE_ORDER: [PieceType; 5] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+    PieceType::Pawn,
+];
+
+impl Board {
+    /// Opponent pieces taken by `color`, grouped by type with how many of each.
+    pub fn captures_by(&self, color: PieceColor) -> Vec<(Piece, usize)> {
+        let captured = color.opposite();
+
+        CAPTURE_ORDER
+            .iter()
+            .filter_map(|&piece_type| {
+                let count = self
+                    .captured_pieces()
+                    .iter()
+                    .filter(|piece| piece.piece_color == captured && piece.piece_type == piece_type)
+                    .count();
+                (count > 0).then(|| (Piece::new(piece_type, captured), count))
+            })
+            .collect()
+    }
+
+    /// Material of `color` on the board minus the opponent's, in pawns. Counting the
+    /// pieces on the board keeps promotions and positions set up from FEN correct.
+    pub fn material_advantage(&self, color: PieceColor) -> i32 {
+        self.board
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|piece| {
+                let value = piece.piece_type.value() as i32;
+                if piece.piece_color == color {
+                    value
+                } else {
+                    -value
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(board: &mut Board, moves: &[&str]) {
+        moves.iter().for_each(|san| {
+            let chess_move = board.parse_san(san).unwrap();
+            board.make_move(&chess_move);
+        });
+    }
+
+    #[test]
+    fn test_captures_grouped_by_type() {
+        let mut board = Board::default();
+        play(
+            &mut board,
+            &["e4", "d5", "exd5", "Qxd5", "Nc3", "Qxa2", "Rxa2"],
+        );
+
+        assert_eq!(
+            board.captures_by(PieceColor::White),
+            [
+                (Piece::new(PieceType::Queen, PieceColor::Black), 1),
+                (Piece::new(PieceType::Pawn, PieceColor::Black), 1),
+            ]
+        );
+        assert_eq!(
+            board.captures_by(PieceColor::Black),
+            [(Piece::new(PieceType::Pawn, PieceColor::White), 2)]
+        );
+        assert_eq!(board.material_advantage(PieceColor::White), 8);
+        assert_eq!(board.material_advantage(PieceColor::Black), -8);
+    }
+
+    #[test]
+    fn test_material_counts_promotions() {
+        let mut board = Board::from_fen("4k3/P7/8/8/8/8/7r/4K3 w - - 0 1").unwrap();
+        assert_eq!(board.material_advantage(PieceColor::White), -4);
+
+        play(&mut board, &["a8=Q+"]);
+        assert_eq!(board.material_advantage(PieceColor::White), 4);
+        assert!(board.captures_by(PieceColor::White).is_empty());
+    }
+}
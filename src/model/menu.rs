@@ -1,8 +1,18 @@
-pub const MENU_ITEMS: [&str; 4] = ["Human vs. Human", "Human vs. Engine", "Online Game", "Help"];
+pub const LOAD_FEN_ITEM: &str = "Load FEN";
+pub const OPEN_PGN_ITEM: &str = "Open PGN";
+
+pub const MENU_ITEMS: [&str; 6] = [
+    "Human vs. Human",
+    "Human vs. Engine",
+    "Online Game",
+    LOAD_FEN_ITEM,
+    OPEN_PGN_ITEM,
+    "Help",
+];
 
 #[derive(Debug, Clone)]
 pub struct MenuState {
-    pub items: [&'static str; 4],
+    pub items: [&'static str; 6],
     pub selected: usize,
 }
 
@@ -16,6 +26,10 @@ impl Default for MenuState {
 }
 
 impl MenuState {
+    pub fn selected_item(&self) -> &'static str {
+        self.items[self.selected]
+    }
+
     pub fn next(&mut self) {
         self.selected = (self.selected + 1) % self.items.len();
     }
@@ -1,9 +1,35 @@
+pub mod attack_map;
+pub mod bitboard;
 pub mod board;
+pub mod castling;
+pub mod chess_move;
+pub mod chess_position;
+pub mod clock;
+pub mod command_bar;
+pub mod drag;
+pub mod fen;
+pub mod game_result;
+pub mod material;
 pub mod menu;
+pub mod move_list;
+pub mod orientation;
+pub mod perft;
+pub mod pgn;
 pub mod pieces;
 pub mod player;
+pub mod promotion;
+pub mod replay;
+pub mod san;
+pub mod takeback;
+pub mod text_input;
+pub mod zobrist;
 
+pub use attack_map::*;
 pub use board::*;
+pub use castling::*;
+pub use chess_move::*;
+pub use chess_position::*;
+pub use game_result::*;
 pub use pieces::*;
 
 pub enum Direction {
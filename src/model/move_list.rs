@@ -0,0 +1,210 @@
+use super::{Board, ChessMove, PieceColor};
+
+/// One numbered line of the move list, with the plies of White's and Black's moves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveListRow {
+    pub number: u32,
+    pub white: Option<usize>,
+    pub black: Option<usize>,
+}
+
+/// The moves of a game written down in SAN, for the move history panel.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MoveList {
+    pub sans: Vec<String>,
+    pub rows: Vec<MoveListRow>,
+}
+
+impl MoveList {
+    /// Plays `moves` from `start`, writing each one down before it is made.
+    pub fn new(start: &Board, moves: &[ChessMove]) -> Self {
+        let mut board = start.clone();
+        let mut move_list = Self::default();
+
+        for chess_move in moves {
+            move_list.push(&board, chess_move);
+            board.make_move(chess_move);
+        }
+
+        move_list
+    }
+
+    /// Writes down `chess_move`, played from `board`, after the moves so far.
+    pub fn push(&mut self, board: &Board, chess_move: &ChessMove) {
+        let ply = self.sans.len();
+        let number = board.fullmove_number;
+        match (board.on_turn, self.rows.last_mut()) {
+            (PieceColor::Black, Some(row)) if row.number == number && row.black.is_none() => {
+                row.black = Some(ply)
+            }
+            (PieceColor::Black, _) => self.rows.push(MoveListRow {
+                number,
+                white: None,
+                black: Some(ply),
+            }),
+            (PieceColor::White, _) => self.rows.push(MoveListRow {
+                number,
+                white: Some(ply),
+                black: None,
+            }),
+        }
+
+        self.sans.push(board.san(chess_move));
+    }
+
+    /// Row holding the move that leads to the position after `ply` moves.
+    pub fn row_of(&self, ply: usize) -> Option<usize> {
+        let index = ply.checked_sub(1)?;
+        self.rows
+            .iter()
+            .position(|row| row.white == Some(index) || row.black == Some(index))
+    }
+}
+
+impl Board {
+    /// The position before the first move in the history.
+    pub fn starting_position(&self) -> Board {
+        let mut board = self.clone();
+        while board.unmake_move().is_some() {}
+        board
+    }
+
+    pub fn move_list(&self) -> MoveList {
+        let moves: Vec<ChessMove> = self
+            .move_history()
+            .iter()
+            .map(|undo_info| undo_info.chess_move.clone())
+            .collect();
+
+        MoveList::new(&self.starting_position(), &moves)
+    }
+}
+
+/// An earlier position of the live game, looked at while the game itself is kept as is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryView {
+    pub ply: usize,
+    pub board: Board,
+}
+
+impl HistoryView {
+    pub fn new(live: &Board) -> Self {
+        let mut board = live.clone();
+        board.selected_position = None;
+        board.currently_legal_moves = None;
+
+        Self {
+            ply: live.move_history().len(),
+            board,
+        }
+    }
+
+    pub fn back(&mut self) -> bool {
+        if self.board.unmake_move().is_none() {
+            return false;
+        }
+
+        self.ply -= 1;
+        true
+    }
+
+    pub fn forward(&mut self, live: &Board) -> bool {
+        let Some(undo_info) = live.move_history().get(self.ply) else {
+            return false;
+        };
+
+        self.board.make_move(&undo_info.chess_move);
+        self.ply += 1;
+        true
+    }
+
+    pub fn to_start(&mut self) {
+        while self.back() {}
+    }
+
+    /// Whether the view has caught up with the live game again.
+    pub fn is_live(&self, live: &Board) -> bool {
+        self.ply == live.move_history().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(board: &mut Board, moves: &[&str]) {
+        moves.iter().for_each(|san| {
+            let chess_move = board.parse_san(san).unwrap();
+            board.make_move(&chess_move);
+        });
+    }
+
+    #[test]
+    fn test_move_list_rows() {
+        let mut board = Board::default();
+        play(&mut board, &["e4", "e5", "Nf3"]);
+        let move_list = board.move_list();
+
+        assert_eq!(move_list.sans, ["e4", "e5", "Nf3"]);
+        assert_eq!(
+            move_list.rows,
+            [
+                MoveListRow {
+                    number: 1,
+                    white: Some(0),
+                    black: Some(1),
+                },
+                MoveListRow {
+                    number: 2,
+                    white: Some(2),
+                    black: None,
+                },
+            ]
+        );
+        assert_eq!(move_list.row_of(0), None);
+        assert_eq!(move_list.row_of(3), Some(1));
+    }
+
+    #[test]
+    fn test_move_list_starting_with_black() {
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 12").unwrap();
+        play(&mut board, &["Kd7", "e4"]);
+
+        assert_eq!(
+            board.move_list().rows,
+            [
+                MoveListRow {
+                    number: 12,
+                    white: None,
+                    black: Some(0),
+                },
+                MoveListRow {
+                    number: 13,
+                    white: Some(1),
+                    black: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_history_view_leaves_live_game() {
+        let mut live = Board::default();
+        play(&mut live, &["d4", "d5", "c4"]);
+        let mut view = HistoryView::new(&live);
+
+        assert!(view.back());
+        assert!(view.back());
+        assert_eq!(view.ply, 1);
+        assert_eq!(view.board.on_turn, PieceColor::Black);
+        assert_eq!(live.move_history().len(), 3);
+
+        view.to_start();
+        assert_eq!(view.board, Board::default());
+        assert!(!view.back());
+
+        while view.forward(&live) {}
+        assert!(view.is_live(&live));
+        assert_eq!(view.board, live);
+    }
+}
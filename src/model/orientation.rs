@@ -0,0 +1,114 @@
+use super::{Direction, PieceColor, Position};
+
+/// Which side of the board is drawn at the bottom of the screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Orientation {
+    #[default]
+    White,
+    Black,
+    /// Turns the board towards whoever is to move, for two players sharing a terminal.
+    SideToMove,
+}
+
+impl Orientation {
+    pub fn bottom(self, on_turn: PieceColor) -> PieceColor {
+        match self {
+            Orientation::White => PieceColor::White,
+            Orientation::Black => PieceColor::Black,
+            Orientation::SideToMove => on_turn,
+        }
+    }
+
+    /// Whether rank 8 is drawn at the bottom, the reverse of `Board::board` row order.
+    pub fn is_flipped(self, on_turn: PieceColor) -> bool {
+        self.bottom(on_turn) == PieceColor::Black
+    }
+
+    /// Shows the board from the other side, which also ends automatic turning.
+    pub fn flip(self, on_turn: PieceColor) -> Self {
+        match self.bottom(on_turn) {
+            PieceColor::White => Orientation::Black,
+            PieceColor::Black => Orientation::White,
+        }
+    }
+
+    /// Switches between following the side to move and keeping the current view.
+    pub fn toggle_auto(self, on_turn: PieceColor) -> Self {
+        match self {
+            Orientation::SideToMove => match on_turn {
+                PieceColor::White => Orientation::White,
+                PieceColor::Black => Orientation::Black,
+            },
+            _ => Orientation::SideToMove,
+        }
+    }
+}
+
+/// Maps a board position to the row and column it is drawn at, and back.
+pub fn screen_position(position: &Position, flipped: bool) -> Position {
+    if flipped {
+        Position {
+            x: 7 - position.x,
+            y: 7 - position.y,
+        }
+    } else {
+        position.clone()
+    }
+}
+
+/// Turns an arrow key direction on screen into a direction on the board.
+pub fn board_direction(direction: Direction, flipped: bool) -> Direction {
+    if !flipped {
+        return direction;
+    }
+
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Down => Direction::Up,
+        Direction::Left => Direction::Right,
+        Direction::Right => Direction::Left,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_side_to_move_follows_turn() {
+        let orientation = Orientation::SideToMove;
+
+        assert!(!orientation.is_flipped(PieceColor::White));
+        assert!(orientation.is_flipped(PieceColor::Black));
+    }
+
+    #[test]
+    fn test_flip_and_toggle_auto() {
+        assert_eq!(
+            Orientation::White.flip(PieceColor::White),
+            Orientation::Black
+        );
+        assert_eq!(
+            Orientation::SideToMove.flip(PieceColor::Black),
+            Orientation::White
+        );
+        assert_eq!(
+            Orientation::Black.toggle_auto(PieceColor::White),
+            Orientation::SideToMove
+        );
+        assert_eq!(
+            Orientation::SideToMove.toggle_auto(PieceColor::Black),
+            Orientation::Black
+        );
+    }
+
+    #[test]
+    fn test_screen_position_round_trips() {
+        let position = Position { x: 6, y: 1 };
+        let screen = screen_position(&position, true);
+
+        assert_eq!(screen, Position { x: 1, y: 6 });
+        assert_eq!(screen_position(&screen, true), position);
+        assert_eq!(screen_position(&position, false), position);
+    }
+}
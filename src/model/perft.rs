@@ -0,0 +1,128 @@
+use super::{ChessMove, ChessPosition};
+
+/// Counts the leaf nodes of the legal move tree down to `depth` plies.
+pub fn perft<P: ChessPosition>(position: &P, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let legal_moves = position.legal_moves();
+
+    // Bulk counting: the leaves are exactly the legal moves one ply above
+    if depth == 1 {
+        return legal_moves.len() as u64;
+    }
+
+    legal_moves
+        .iter()
+        .map(|chess_move| {
+            let mut next = position.clone();
+            next.make_move(chess_move);
+            perft(&next, depth - 1)
+        })
+        .sum()
+}
+
+/// Splits the perft count by root move, for comparing against other engines.
+pub fn divide<P: ChessPosition>(position: &P, depth: u32) -> Vec<(ChessMove, u64)> {
+    position
+        .legal_moves()
+        .into_iter()
+        .map(|chess_move| {
+            let mut next = position.clone();
+            next.make_move(&chess_move);
+            let nodes = perft(&next, depth.saturating_sub(1));
+            (chess_move, nodes)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{bitboard::BitboardPosition, fen::STARTING_FEN, Board};
+
+    use super::*;
+
+    const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    const POSITION_3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+    const POSITION_4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+    const POSITION_4_MIRRORED: &str =
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1";
+    const POSITION_5: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+    const POSITION_6: &str =
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+    fn assert_perft(fen: &str, expected_nodes: &[u64]) {
+        let position = BitboardPosition::from(&Board::from_fen(fen).unwrap());
+
+        for (depth, expected) in expected_nodes.iter().enumerate() {
+            assert_eq!(
+                perft(&position, depth as u32 + 1),
+                *expected,
+                "{} at depth {}",
+                fen,
+                depth + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_perft_starting_position() {
+        assert_perft(STARTING_FEN, &[20, 400, 8_902, 197_281]);
+    }
+
+    #[test]
+    fn test_perft_kiwipete() {
+        assert_perft(KIWIPETE, &[48, 2_039, 97_862]);
+    }
+
+    #[test]
+    fn test_perft_position_3() {
+        assert_perft(POSITION_3, &[14, 191, 2_812, 43_238, 674_624]);
+    }
+
+    #[test]
+    fn test_perft_position_4() {
+        assert_perft(POSITION_4, &[6, 264, 9_467, 422_333]);
+        assert_perft(POSITION_4_MIRRORED, &[6, 264, 9_467, 422_333]);
+    }
+
+    #[test]
+    fn test_perft_position_5() {
+        assert_perft(POSITION_5, &[44, 1_486, 62_379]);
+    }
+
+    #[test]
+    fn test_perft_position_6() {
+        assert_perft(POSITION_6, &[46, 2_079, 89_890]);
+    }
+
+    #[test]
+    #[ignore = "slow in debug builds, run with --release --ignored"]
+    fn test_perft_deep() {
+        assert_perft(STARTING_FEN, &[20, 400, 8_902, 197_281, 4_865_609]);
+        assert_perft(KIWIPETE, &[48, 2_039, 97_862, 4_085_603]);
+        assert_perft(POSITION_5, &[44, 1_486, 62_379, 2_103_487]);
+        assert_perft(POSITION_6, &[46, 2_079, 89_890, 3_894_594]);
+    }
+
+    #[test]
+    fn test_board_and_bitboard_agree() {
+        for fen in [KIWIPETE, POSITION_3, POSITION_4, POSITION_5, POSITION_6] {
+            let board = Board::from_fen(fen).unwrap();
+            let position = BitboardPosition::from(&board);
+
+            assert_eq!(perft(&board, 2), perft(&position, 2), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_divide_sums_to_perft() {
+        let position = BitboardPosition::from(&Board::from_fen(KIWIPETE).unwrap());
+
+        let divided = divide(&position, 2);
+
+        assert_eq!(divided.len(), 48);
+        assert_eq!(divided.iter().map(|(_, nodes)| nodes).sum::<u64>(), 2_039);
+    }
+}
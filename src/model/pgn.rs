@@ -0,0 +1,525 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    iter::Peekable,
+    str::Chars,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{
+    fen::{FenError, STARTING_FEN},
+    san::SanError,
+    Board, ChessMove, PieceColor,
+};
+
+const MAX_LINE_LENGTH: usize = 80;
+
+/// The Seven Tag Roster, minus the result which always comes from the board.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgnTags {
+    pub event: String,
+    pub site: String,
+    pub date: String,
+    pub round: String,
+    pub white: String,
+    pub black: String,
+}
+
+impl Default for PgnTags {
+    fn default() -> Self {
+        Self {
+            event: String::from("Casual Game"),
+            site: String::from("?"),
+            date: String::from("????.??.??"),
+            round: String::from("-"),
+            white: String::from("?"),
+            black: String::from("?"),
+        }
+    }
+}
+
+impl PgnTags {
+    /// Default tags dated today.
+    pub fn today() -> Self {
+        Self {
+            date: pgn_date(SystemTime::now()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Days since 1970-01-01 to a (year, month, day) civil date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+fn seconds_since_epoch(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
+
+/// Date in the `YYYY.MM.DD` format used by the Date tag, in UTC.
+pub fn pgn_date(time: SystemTime) -> String {
+    let (year, month, day) = civil_from_days(seconds_since_epoch(time).div_euclid(86_400));
+    format!("{:04}.{:02}.{:02}", year, month, day)
+}
+
+/// Default file name for a saved game, e.g. `chessterm-2024.07.21-183005.pgn`.
+pub fn pgn_file_name(time: SystemTime) -> String {
+    let seconds_of_day = seconds_since_epoch(time).rem_euclid(86_400);
+    format!(
+        "chessterm-{}-{:02}{:02}{:02}.pgn",
+        pgn_date(time),
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    )
+}
+
+fn tag_pair(name: &str, value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("[{} \"{}\"]\n", name, escaped)
+}
+
+/// Joins movetext tokens into lines no longer than the PGN export limit.
+fn wrap_movetext(tokens: &[String]) -> String {
+    let mut lines = vec![String::new()];
+
+    for token in tokens {
+        let line = lines.last_mut().expect("there is always a line");
+        if line.is_empty() {
+            line.push_str(token);
+        } else if line.len() + 1 + token.len() <= MAX_LINE_LENGTH {
+            line.push(' ');
+            line.push_str(token);
+        } else {
+            lines.push(token.clone());
+        }
+    }
+
+    lines.join("\n")
+}
+
+impl Board {
+    /// Exports the game played so far, finished or not, as PGN.
+    pub fn to_pgn(&self, tags: &PgnTags) -> String {
+        let mut replay = self.starting_position();
+        let starting_fen = replay.to_fen();
+
+        let mut tokens = Vec::with_capacity(self.move_history().len() * 3 / 2 + 1);
+        for (ply, undo_info) in self.move_history().iter().enumerate() {
+            match replay.on_turn {
+                PieceColor::White => tokens.push(format!("{}.", replay.fullmove_number)),
+                PieceColor::Black if ply == 0 => {
+                    tokens.push(format!("{}...", replay.fullmove_number))
+                }
+                PieceColor::Black => {}
+            }
+            tokens.push(replay.san(&undo_info.chess_move));
+            replay.make_move(&undo_info.chess_move);
+        }
+        tokens.push(self.result.pgn_token().to_string());
+
+        let mut pgn = [
+            ("Event", tags.event.as_str()),
+            ("Site", tags.site.as_str()),
+            ("Date", tags.date.as_str()),
+            ("Round", tags.round.as_str()),
+            ("White", tags.white.as_str()),
+            ("Black", tags.black.as_str()),
+            ("Result", self.result.pgn_token()),
+        ]
+        .iter()
+        .map(|(name, value)| tag_pair(name, value))
+        .collect::<String>();
+
+        if starting_fen != STARTING_FEN {
+            pgn.push_str(&tag_pair("SetUp", "1"));
+            pgn.push_str(&tag_pair("FEN", &starting_fen));
+        }
+
+        pgn.push('\n');
+        pgn.push_str(&wrap_movetext(&tokens));
+        pgn.push('\n');
+
+        pgn
+    }
+}
+
+/// A game read from a PGN file, with its moves still in SAN.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PgnGame {
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<String>,
+    pub result: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PgnError {
+    InvalidFen(FenError),
+    InvalidMove {
+        move_number: u32,
+        side: PieceColor,
+        error: SanError,
+    },
+}
+
+impl Display for PgnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PgnError::InvalidFen(error) => write!(f, "invalid FEN tag: {}", error),
+            PgnError::InvalidMove {
+                move_number,
+                side,
+                error,
+            } => {
+                let dots = match side {
+                    PieceColor::White => ".",
+                    PieceColor::Black => "...",
+                };
+                write!(f, "move {}{} {}", move_number, dots, error)
+            }
+        }
+    }
+}
+
+impl Error for PgnError {}
+
+impl PgnGame {
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag_name, _)| tag_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Board the game starts from, honouring the FEN tag of set up positions.
+    pub fn starting_board(&self) -> Result<Board, PgnError> {
+        match self.tag("FEN") {
+            Some(fen) => Board::from_fen(fen).map_err(PgnError::InvalidFen),
+            None => Ok(Board::default()),
+        }
+    }
+
+    /// Resolves every SAN move against the position it is played in.
+    pub fn chess_moves(&self) -> Result<Vec<ChessMove>, PgnError> {
+        let mut board = self.starting_board()?;
+        let mut chess_moves = Vec::with_capacity(self.moves.len());
+
+        for san in &self.moves {
+            let chess_move = board
+                .parse_san(san)
+                .map_err(|error| PgnError::InvalidMove {
+                    move_number: board.fullmove_number,
+                    side: board.on_turn,
+                    error,
+                })?;
+
+            board.make_move(&chess_move);
+            chess_moves.push(chess_move);
+        }
+
+        Ok(chess_moves)
+    }
+}
+
+/// Reads a `[Name "value"]` tag pair, with the opening bracket already consumed.
+fn parse_tag_pair(chars: &mut Peekable<Chars>) -> Option<(String, String)> {
+    let mut name = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            ']' if !in_value => break,
+            '"' => in_value = !in_value,
+            '\\' if in_value => value.extend(chars.next()),
+            _ if in_value => value.push(c),
+            _ if !c.is_whitespace() && value.is_empty() => name.push(c),
+            _ => {}
+        }
+    }
+
+    (!name.is_empty()).then_some((name, value))
+}
+
+/// Skips a possibly nested variation, with the opening parenthesis already consumed.
+fn skip_variation(chars: &mut Peekable<Chars>) {
+    let mut depth = 1;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '{' => chars.by_ref().take_while(|c| *c != '}').for_each(drop),
+            _ => {}
+        }
+        if depth == 0 {
+            break;
+        }
+    }
+}
+
+/// Handles one movetext token, returning true when it ends the game.
+fn push_movetext_token(game: &mut PgnGame, token: &str) -> bool {
+    if matches!(token, "1-0" | "0-1" | "1/2-1/2" | "*") {
+        game.result = Some(token.to_string());
+        return true;
+    }
+    if token.starts_with('$') {
+        return false;
+    }
+
+    // Move numbers may be glued to the move, as in `12.e4` or `12...Nf6`. Only
+    // digits followed by dots are a number, so castling written `0-0` is kept.
+    let after_digits = token.trim_start_matches(|c: char| c.is_ascii_digit());
+    let san = if after_digits.is_empty() || after_digits.starts_with('.') {
+        after_digits.trim_start_matches('.')
+    } else {
+        token
+    };
+    if !san.is_empty() {
+        game.moves.push(san.to_string());
+    }
+
+    false
+}
+
+/// Parses every game of a PGN text. Comments, NAGs, variations and escape lines
+/// are skipped; moves are only checked for legality by `PgnGame::chess_moves`.
+pub fn parse_pgn(text: &str) -> Vec<PgnGame> {
+    let mut games = Vec::new();
+    let mut game = PgnGame::default();
+    let mut chars = text.chars().peekable();
+    let mut at_line_start = true;
+
+    let finish = |games: &mut Vec<PgnGame>, game: &mut PgnGame| {
+        let game = std::mem::take(game);
+        if !game.tags.is_empty() || !game.moves.is_empty() {
+            games.push(game);
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        let line_start = at_line_start;
+        at_line_start = c == '\n';
+
+        match c {
+            '%' if line_start => {
+                chars.by_ref().take_while(|c| *c != '\n').for_each(drop);
+                at_line_start = true;
+            }
+            ';' => {
+                chars.by_ref().take_while(|c| *c != '\n').for_each(drop);
+                at_line_start = true;
+            }
+            '{' => chars.by_ref().take_while(|c| *c != '}').for_each(drop),
+            '(' => skip_variation(&mut chars),
+            '[' => {
+                // A tag section after movetext starts the next game
+                if !game.moves.is_empty() {
+                    finish(&mut games, &mut game);
+                }
+                game.tags.extend(parse_tag_pair(&mut chars));
+            }
+            _ if c.is_whitespace() || c == ')' || c == '}' || c == ']' => {}
+            _ => {
+                let mut token = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || "{}()[];".contains(next) {
+                        break;
+                    }
+                    token.push(next);
+                    chars.next();
+                }
+
+                if push_movetext_token(&mut game, &token) {
+                    finish(&mut games, &mut game);
+                }
+            }
+        }
+    }
+
+    finish(&mut games, &mut game);
+    games
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn play(board: &mut Board, coordinates: &[&str]) {
+        for coordinate in coordinates {
+            let chess_move = board
+                .legal_moves()
+                .into_iter()
+                .find(|chess_move| chess_move.to_string() == *coordinate)
+                .unwrap();
+            assert!(board.play_move(&chess_move));
+        }
+    }
+
+    #[test]
+    fn test_finished_game_export() {
+        let mut board = Board::default();
+        play(&mut board, &["f2f3", "e7e5", "g2g4", "d8h4"]);
+
+        let tags = PgnTags {
+            date: String::from("2024.07.21"),
+            white: String::from("Fool"),
+            black: String::from("Opponent \"the quick\""),
+            ..PgnTags::default()
+        };
+
+        assert_eq!(
+            board.to_pgn(&tags),
+            "[Event \"Casual Game\"]\n\
+             [Site \"?\"]\n\
+             [Date \"2024.07.21\"]\n\
+             [Round \"-\"]\n\
+             [White \"Fool\"]\n\
+             [Black \"Opponent \\\"the quick\\\"\"]\n\
+             [Result \"0-1\"]\n\
+             \n\
+             1. f3 e5 2. g4 Qh4# 0-1\n"
+        );
+    }
+
+    #[test]
+    fn test_in_progress_game_from_fen_export() {
+        let fen = "4k3/8/8/8/8/8/4P3/4K3 b - - 0 40";
+        let mut board = Board::from_fen(fen).unwrap();
+        play(&mut board, &["e8d7", "e2e4"]);
+
+        let pgn = board.to_pgn(&PgnTags::default());
+
+        assert!(pgn.contains(
+            "[Result \"*\"]\n[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 40\"]\n"
+        ));
+        assert!(pgn.ends_with("\n40... Kd7 41. e4 *\n"));
+    }
+
+    #[test]
+    fn test_long_movetext_is_wrapped() {
+        let tokens: Vec<String> = (1..=40).map(|number| format!("{}. Nf3", number)).collect();
+
+        let movetext = wrap_movetext(&tokens);
+
+        assert!(movetext.lines().count() > 1);
+        assert!(movetext.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(movetext.replace('\n', " "), tokens.join(" "));
+    }
+
+    #[test]
+    fn test_dates() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_721_586_605);
+
+        assert_eq!(pgn_date(UNIX_EPOCH), "1970.01.01");
+        assert_eq!(pgn_date(time), "2024.07.21");
+        assert_eq!(pgn_file_name(time), "chessterm-2024.07.21-183005.pgn");
+    }
+
+    const TWO_GAMES: &str = r#"% exported by hand
+[Event "Club \"Open\""]
+[White "Alice"]
+[Black "Bob"]
+[Result "1-0"]
+
+1. e4 {best by test} e5 2. Nf3 $1 (2. f4 exf4 (2... d5) 3. Nf3) 2... Nc6
+3.Bb5 a6!? ; the Morphy defence
+4. Ba4 Nf6 5. O-O Be7 1-0
+
+[Event "Second"]
+[SetUp "1"]
+[FEN "4k3/8/8/8/8/8/4P3/4K3 b - - 0 40"]
+
+40... Kd7 41. e4 *
+"#;
+
+    #[test]
+    fn test_parse_multiple_games() {
+        let games = parse_pgn(TWO_GAMES);
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].tag("Event"), Some("Club \"Open\""));
+        assert_eq!(games[0].tag("White"), Some("Alice"));
+        assert_eq!(
+            games[0].moves,
+            ["e4", "e5", "Nf3", "Nc6", "Bb5", "a6!?", "Ba4", "Nf6", "O-O", "Be7"]
+        );
+        assert_eq!(games[0].result.as_deref(), Some("1-0"));
+        assert_eq!(games[1].moves, ["Kd7", "e4"]);
+        assert_eq!(games[1].result.as_deref(), Some("*"));
+    }
+
+    #[test]
+    fn test_resolve_moves() {
+        let games = parse_pgn(TWO_GAMES);
+
+        let chess_moves = games[0].chess_moves().unwrap();
+        assert_eq!(chess_moves.len(), 10);
+        assert_eq!(chess_moves[8].to_string(), "e1g1");
+
+        let chess_moves = games[1].chess_moves().unwrap();
+        assert_eq!(chess_moves[0].to_string(), "e8d7");
+    }
+
+    #[test]
+    fn test_castling_with_zeros() {
+        let games = parse_pgn("1. e4 e5 2. Nf3 Nc6 3. Bc4 d6 4.0-0 Be6 5. d3 Qd7 6. Nc3 0-0-0 *");
+
+        assert_eq!(games[0].moves[6], "0-0");
+        assert_eq!(games[0].moves[11], "0-0-0");
+        let chess_moves = games[0].chess_moves().unwrap();
+        assert_eq!(chess_moves[6].to_string(), "e1g1");
+        assert_eq!(chess_moves[11].to_string(), "e8c8");
+    }
+
+    #[test]
+    fn test_illegal_move_is_reported() {
+        let games = parse_pgn("1. e4 e5 2. Ke3 *");
+
+        let error = games[0].chess_moves().unwrap_err();
+        assert_eq!(
+            error,
+            PgnError::InvalidMove {
+                move_number: 2,
+                side: PieceColor::White,
+                error: SanError::Illegal(String::from("Ke3"))
+            }
+        );
+        assert_eq!(error.to_string(), "move 2. Ke3 is not a legal move here");
+    }
+
+    #[test]
+    fn test_exported_game_reads_back() {
+        let mut board = Board::default();
+        play(
+            &mut board,
+            &["e2e4", "d7d5", "e4d5", "g8f6", "f1b5", "c7c6"],
+        );
+
+        let games = parse_pgn(&board.to_pgn(&PgnTags::default()));
+
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].chess_moves().unwrap().len(), 6);
+        assert_eq!(games[0].moves[4], "Bb5+");
+    }
+}
@@ -3,18 +3,15 @@ use crate::model::{explore_moves, Board, Move, Position};
 pub struct Bishop;
 
 impl Move for Bishop {
-    fn available_moves(board: &Board) -> Option<Vec<Position>> {
-        let selected_position = board.selected_position.clone()?;
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>> {
         let mut legal_moves: Vec<Position> = Vec::with_capacity(13);
-        let color = board
-            .get_piece_color_in_position(selected_position.clone())
-            .unwrap();
+        let color = board.get_piece_color_in_position(from.clone())?;
 
         // Explore moves in all four directions using recursion
-        explore_moves(&selected_position, &mut legal_moves, board, color, 1, 1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, -1, 1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, -1, -1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 1, -1);
+        explore_moves(from, &mut legal_moves, board, color, 1, 1);
+        explore_moves(from, &mut legal_moves, board, color, -1, 1);
+        explore_moves(from, &mut legal_moves, board, color, -1, -1);
+        explore_moves(from, &mut legal_moves, board, color, 1, -1);
 
         if legal_moves.is_empty() {
             None
@@ -32,10 +29,9 @@ mod tests {
 
     #[test]
     fn test_bishop_initial_position() {
-        let mut board = Board::default();
-        board.select_position(&Position { x: 7, y: 2 });
+        let board = Board::default();
 
-        let legal_moves = Bishop::available_moves(&board);
+        let legal_moves = Bishop::available_moves(&board, &Position { x: 7, y: 2 });
 
         assert!(legal_moves.is_none());
     }
@@ -44,9 +40,8 @@ mod tests {
     fn test_bishop_free_board() {
         let mut board = Board::init_empty();
         board.board[5][4] = Some(Piece::new(PieceType::Bishop, PieceColor::White));
-        board.select_position(&Position { x: 5, y: 4 });
 
-        let legal_moves = Bishop::available_moves(&board).unwrap();
+        let legal_moves = Bishop::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 1, y: 0 },
@@ -74,10 +69,9 @@ mod tests {
         let mut board = Board::init_empty();
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Bishop, PieceColor::Black));
-        board.select_position(&Position { x: 5, y: 4 });
         board.board[3][2] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
 
-        let legal_moves = Bishop::available_moves(&board).unwrap();
+        let legal_moves = Bishop::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 3, y: 2 },
@@ -103,14 +97,13 @@ mod tests {
         let mut board = Board::init_empty();
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Bishop, PieceColor::Black));
-        board.select_position(&Position { x: 5, y: 4 });
         board.board[3][2] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[6][3] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
         board.board[2][7] = Some(Piece::new(PieceType::Knight, PieceColor::Black));
 
-        let legal_moves = Bishop::available_moves(&board).unwrap();
+        let legal_moves = Bishop::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
-        let expected_moves = vec![
+        let expected_moves = [
             Position { x: 3, y: 2 },
             Position { x: 4, y: 3 },
             Position { x: 6, y: 5 },
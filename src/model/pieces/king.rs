@@ -1,13 +1,10 @@
-use crate::model::{Board, Move, Position};
+use crate::model::{home_row, Board, CastlingSide, Move, PieceColor, PieceType, Position};
 
 pub struct King;
 
 impl Move for King {
-    fn available_moves(board: &Board) -> Option<Vec<Position>> {
-        let (x, y) = match &board.selected_position {
-            Some(pos) => (pos.x, pos.y),
-            None => return None,
-        };
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>> {
+        let (x, y) = (from.x, from.y);
 
         let selected_piece_color = board.get_piece_color_in_position(Position { x, y })?;
 
@@ -45,6 +42,8 @@ impl Move for King {
             }
         }
 
+        legal_moves.extend(Self::get_castling_moves(board, from, selected_piece_color));
+
         if legal_moves.is_empty() {
             None
         } else {
@@ -53,18 +52,58 @@ impl Move for King {
     }
 }
 
+impl King {
+    /// Castling targets allowed by the castling rights and the squares between
+    /// king and rook. Whether the king passes through check is left to the board.
+    fn get_castling_moves(board: &Board, position: &Position, color: PieceColor) -> Vec<Position> {
+        let row = home_row(color);
+        let mut castling_moves = Vec::with_capacity(2);
+
+        if position.x != row || position.y != 4 {
+            return castling_moves;
+        }
+
+        for side in [CastlingSide::KingSide, CastlingSide::QueenSide] {
+            if !board.castling_rights.can_castle(color, side) {
+                continue;
+            }
+
+            let rook_column = side.rook_from_column();
+            let has_rook = matches!(
+                board.board[row][rook_column],
+                Some(piece) if piece.piece_type == PieceType::Rook && piece.piece_color == color
+            );
+
+            let (start, end) = if rook_column > position.y {
+                (position.y + 1, rook_column)
+            } else {
+                (rook_column + 1, position.y)
+            };
+            let is_path_clear = (start..end).all(|y| board.is_cell_empty(Position { x: row, y }));
+
+            if has_rook && is_path_clear {
+                castling_moves.push(Position {
+                    x: row,
+                    y: side.king_to_column(),
+                });
+            }
+        }
+
+        castling_moves
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::model::{Piece, PieceColor, PieceType};
+    use crate::model::{CastlingRights, Piece};
 
     use super::*;
 
     #[test]
     fn test_king_initial_board() {
-        let mut board = Board::default();
-        board.selected_position = Some(Position { x: 7, y: 4 });
+        let board = Board::default();
 
-        let legal_moves = King::available_moves(&board);
+        let legal_moves = King::available_moves(&board, &Position { x: 7, y: 4 });
 
         assert!(legal_moves.is_none());
     }
@@ -73,11 +112,10 @@ mod tests {
     fn test_king_free_board() {
         let mut board = Board::init_empty();
         board.board[5][5] = Some(Piece::new(PieceType::King, PieceColor::Black));
-        board.selected_position = Some(Position { x: 5, y: 5 });
 
-        let legal_moves = King::available_moves(&board).unwrap();
+        let legal_moves = King::available_moves(&board, &Position { x: 5, y: 5 }).unwrap();
 
-        let expected_moves = vec![
+        let expected_moves = [
             Position { x: 5, y: 4 },
             Position { x: 5, y: 6 },
             Position { x: 4, y: 6 },
@@ -98,16 +136,15 @@ mod tests {
     #[test]
     fn test_king_with_captures_and_blocking_pieces() {
         let mut board = Board::init_empty();
-        board.selected_position = Some(Position { x: 5, y: 5 });
         board.board[5][5] = Some(Piece::new(PieceType::King, PieceColor::White));
         board.board[4][5] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[5][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[6][4] = Some(Piece::new(PieceType::Knight, PieceColor::Black));
         board.board[6][6] = Some(Piece::new(PieceType::Knight, PieceColor::Black));
 
-        let legal_moves = King::available_moves(&board).unwrap();
+        let legal_moves = King::available_moves(&board, &Position { x: 5, y: 5 }).unwrap();
 
-        let expected_moves = vec![
+        let expected_moves = [
             Position { x: 5, y: 6 },
             Position { x: 4, y: 6 },
             Position { x: 4, y: 4 },
@@ -122,4 +159,36 @@ mod tests {
             assert!(legal_moves.contains(position));
         }
     }
+
+    #[test]
+    fn test_king_castling_both_sides() {
+        let mut board = Board::init_empty();
+        board.castling_rights = CastlingRights::default();
+        board.board[7][4] = Some(Piece::new(PieceType::King, PieceColor::White));
+        board.board[7][0] = Some(Piece::new(PieceType::Rook, PieceColor::White));
+        board.board[7][7] = Some(Piece::new(PieceType::Rook, PieceColor::White));
+
+        let legal_moves = King::available_moves(&board, &Position { x: 7, y: 4 }).unwrap();
+
+        assert!(legal_moves.contains(&Position { x: 7, y: 6 }));
+        assert!(legal_moves.contains(&Position { x: 7, y: 2 }));
+    }
+
+    #[test]
+    fn test_king_castling_blocked_or_without_rights() {
+        let mut board = Board::init_empty();
+        board.castling_rights = CastlingRights::default();
+        board
+            .castling_rights
+            .revoke(PieceColor::Black, CastlingSide::KingSide);
+        board.board[0][4] = Some(Piece::new(PieceType::King, PieceColor::Black));
+        board.board[0][0] = Some(Piece::new(PieceType::Rook, PieceColor::Black));
+        board.board[0][1] = Some(Piece::new(PieceType::Knight, PieceColor::Black));
+        board.board[0][7] = Some(Piece::new(PieceType::Rook, PieceColor::Black));
+
+        let legal_moves = King::available_moves(&board, &Position { x: 0, y: 4 }).unwrap();
+
+        assert!(!legal_moves.contains(&Position { x: 0, y: 6 }));
+        assert!(!legal_moves.contains(&Position { x: 0, y: 2 }));
+    }
 }
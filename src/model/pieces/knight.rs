@@ -3,13 +3,8 @@ use crate::model::{Board, Move, Position};
 pub struct Knight;
 
 impl Move for Knight {
-    fn available_moves(board: &Board) -> Option<Vec<Position>> {
-        board.selected_position.as_ref()?;
-
-        let (x, y) = match &board.selected_position {
-            Some(pos) => (pos.x, pos.y),
-            None => return None,
-        };
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>> {
+        let (x, y) = (from.x, from.y);
 
         let selected_piece_color = board.get_piece_color_in_position(Position { x, y })?;
 
@@ -63,12 +58,11 @@ mod tests {
 
     #[test]
     fn test_knight_initial_moves() {
-        let mut board = Board::default();
-        board.select_position(&Position { x: 7, y: 1 });
+        let board = Board::default();
 
-        let legal_moves = Knight::available_moves(&board).unwrap();
+        let legal_moves = Knight::available_moves(&board, &Position { x: 7, y: 1 }).unwrap();
 
-        let expected_moves = vec![Position { x: 5, y: 0 }, Position { x: 5, y: 2 }];
+        let expected_moves = [Position { x: 5, y: 0 }, Position { x: 5, y: 2 }];
 
         assert_eq!(legal_moves.len(), expected_moves.len());
 
@@ -80,11 +74,10 @@ mod tests {
     #[test]
     fn test_knight_no_legal_moves() {
         let mut board = Board::default();
-        board.select_position(&Position { x: 7, y: 1 });
         board.board[5][0] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[5][2] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
 
-        let legal_moves = Knight::available_moves(&board);
+        let legal_moves = Knight::available_moves(&board, &Position { x: 7, y: 1 });
 
         assert!(legal_moves.is_none());
     }
@@ -93,11 +86,10 @@ mod tests {
     fn test_knight_free_board() {
         let mut board = Board::init_empty();
         board.board[4][4] = Some(Piece::new(PieceType::Knight, PieceColor::White));
-        board.select_position(&Position { x: 4, y: 4 });
 
-        let legal_moves = Knight::available_moves(&board).unwrap();
+        let legal_moves = Knight::available_moves(&board, &Position { x: 4, y: 4 }).unwrap();
 
-        let expected_moves = vec![
+        let expected_moves = [
             Position { x: 2, y: 3 },
             Position { x: 2, y: 5 },
             Position { x: 3, y: 6 },
@@ -123,11 +115,10 @@ mod tests {
         board.board[5][6] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[3][2] = Some(Piece::new(PieceType::Knight, PieceColor::Black));
         board.board[2][3] = Some(Piece::new(PieceType::Knight, PieceColor::Black));
-        board.select_position(&Position { x: 4, y: 4 });
 
-        let legal_moves = Knight::available_moves(&board).unwrap();
+        let legal_moves = Knight::available_moves(&board, &Position { x: 4, y: 4 }).unwrap();
 
-        let expected_moves = vec![
+        let expected_moves = [
             Position { x: 2, y: 3 },
             Position { x: 2, y: 5 },
             Position { x: 3, y: 6 },
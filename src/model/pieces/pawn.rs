@@ -3,16 +3,11 @@ use crate::model::{Board, Move, PieceColor, Position};
 pub struct Pawn;
 
 impl Move for Pawn {
-    fn available_moves(board: &Board) -> Option<Vec<Position>> {
-        let (x, y) = match &board.selected_position {
-            Some(pos) => (pos.x, pos.y),
-            None => return None,
-        };
-
-        let legal_moves = match &board.board[x][y] {
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>> {
+        let legal_moves = match &board.board[from.x][from.y] {
             Some(piece) => match piece.piece_color {
-                PieceColor::White => Self::get_legal_moves_for_white(board),
-                PieceColor::Black => Self::get_legal_moves_for_black(board),
+                PieceColor::White => Self::get_legal_moves_for_white(board, from),
+                PieceColor::Black => Self::get_legal_moves_for_black(board, from),
             },
             None => vec![],
         };
@@ -26,10 +21,14 @@ impl Move for Pawn {
 }
 
 impl Pawn {
-    fn get_legal_moves_for_white(board: &Board) -> Vec<Position> {
-        let position = board.selected_position.clone().unwrap();
+    fn get_legal_moves_for_white(board: &Board, from: &Position) -> Vec<Position> {
         let mut legal_moves = Vec::with_capacity(4);
-        let (x, y) = (position.x, position.y);
+        let (x, y) = (from.x, from.y);
+
+        // A pawn on the last rank has been promoted and cannot move as a pawn
+        if x == 0 {
+            return legal_moves;
+        }
 
         match board.board[x - 1][y] {
             Some(_) => {}
@@ -67,13 +66,24 @@ impl Pawn {
             };
         }
 
+        // En passant capture of a pawn that just made a double move
+        if let Some(target) = &board.en_passant_target {
+            if target.x + 1 == x && target.y.abs_diff(y) == 1 {
+                legal_moves.push(target.clone());
+            }
+        }
+
         legal_moves
     }
 
-    fn get_legal_moves_for_black(board: &Board) -> Vec<Position> {
-        let position = board.selected_position.clone().unwrap();
+    fn get_legal_moves_for_black(board: &Board, from: &Position) -> Vec<Position> {
         let mut legal_moves = Vec::with_capacity(4);
-        let (x, y) = (position.x, position.y);
+        let (x, y) = (from.x, from.y);
+
+        // A pawn on the last rank has been promoted and cannot move as a pawn
+        if x == 7 {
+            return legal_moves;
+        }
 
         match board.board[x + 1][y] {
             Some(_) => {}
@@ -111,6 +121,13 @@ impl Pawn {
             };
         }
 
+        // En passant capture of a pawn that just made a double move
+        if let Some(target) = &board.en_passant_target {
+            if target.x == x + 1 && target.y.abs_diff(y) == 1 {
+                legal_moves.push(target.clone());
+            }
+        }
+
         legal_moves
     }
 }
@@ -123,10 +140,9 @@ mod tests {
 
     #[test]
     fn test_white_pawn_initial_moves() {
-        let mut board = Board::default();
-        board.select_position(&Position { x: 6, y: 3 });
+        let board = Board::default();
 
-        let legal_moves = Pawn::get_legal_moves_for_white(&board);
+        let legal_moves = Pawn::get_legal_moves_for_white(&board, &Position { x: 6, y: 3 });
 
         let expected_moves = vec![Position { x: 5, y: 3 }, Position { x: 4, y: 3 }];
 
@@ -136,10 +152,9 @@ mod tests {
     #[test]
     fn test_white_pawn_blocked_moves() {
         let mut board = Board::default();
-        board.select_position(&Position { x: 6, y: 3 });
         board.board[5][3] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
 
-        let legal_moves = Pawn::get_legal_moves_for_white(&board);
+        let legal_moves = Pawn::get_legal_moves_for_white(&board, &Position { x: 6, y: 3 });
 
         let expected_moves: Vec<Position> = vec![];
 
@@ -150,9 +165,8 @@ mod tests {
     fn test_black_pawn_initial_moves() {
         let mut board = Board::default();
         board.on_turn = PieceColor::Black;
-        board.select_position(&Position { x: 1, y: 3 });
 
-        let legal_moves = Pawn::get_legal_moves_for_black(&board);
+        let legal_moves = Pawn::get_legal_moves_for_black(&board, &Position { x: 1, y: 3 });
 
         let expected_moves = vec![Position { x: 2, y: 3 }, Position { x: 3, y: 3 }];
 
@@ -163,10 +177,9 @@ mod tests {
     fn test_black_pawn_blocked_moves() {
         let mut board = Board::default();
         board.on_turn = PieceColor::Black;
-        board.select_position(&Position { x: 1, y: 3 });
         board.board[2][3] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
 
-        let legal_moves = Pawn::get_legal_moves_for_black(&board);
+        let legal_moves = Pawn::get_legal_moves_for_black(&board, &Position { x: 1, y: 3 });
 
         let expected_moves: Vec<Position> = vec![];
 
@@ -179,9 +192,8 @@ mod tests {
         board.board[4][3] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[3][2] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
         board.board[3][4] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
-        board.select_position(&Position { x: 4, y: 3 });
 
-        let legal_moves = Pawn::get_legal_moves_for_white(&board);
+        let legal_moves = Pawn::get_legal_moves_for_white(&board, &Position { x: 4, y: 3 });
 
         let expected_moves = vec![
             Position { x: 3, y: 3 },
@@ -199,9 +211,8 @@ mod tests {
         board.board[3][3] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
         board.board[4][2] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[4][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
-        board.selected_position = Some(Position { x: 3, y: 3 });
 
-        let legal_moves = Pawn::get_legal_moves_for_black(&board);
+        let legal_moves = Pawn::get_legal_moves_for_black(&board, &Position { x: 3, y: 3 });
 
         let expected_moves = vec![
             Position { x: 4, y: 3 },
@@ -211,4 +222,57 @@ mod tests {
 
         assert_eq!(legal_moves, expected_moves);
     }
+
+    #[test]
+    fn test_white_pawn_en_passant() {
+        let mut board = Board::init_empty();
+        board.board[3][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        board.board[3][3] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
+        board.en_passant_target = Some(Position { x: 2, y: 3 });
+
+        let legal_moves = Pawn::get_legal_moves_for_white(&board, &Position { x: 3, y: 4 });
+
+        let expected_moves = vec![Position { x: 2, y: 4 }, Position { x: 2, y: 3 }];
+
+        assert_eq!(legal_moves, expected_moves);
+    }
+
+    #[test]
+    fn test_black_pawn_en_passant() {
+        let mut board = Board::init_empty();
+        board.on_turn = PieceColor::Black;
+        board.board[4][2] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
+        board.board[4][1] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        board.en_passant_target = Some(Position { x: 5, y: 1 });
+
+        let legal_moves = Pawn::get_legal_moves_for_black(&board, &Position { x: 4, y: 2 });
+
+        let expected_moves = vec![Position { x: 5, y: 2 }, Position { x: 5, y: 1 }];
+
+        assert_eq!(legal_moves, expected_moves);
+    }
+
+    #[test]
+    fn test_pawn_en_passant_target_out_of_reach() {
+        let mut board = Board::init_empty();
+        board.board[3][6] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        board.board[3][3] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
+        board.en_passant_target = Some(Position { x: 2, y: 3 });
+
+        let legal_moves = Pawn::get_legal_moves_for_white(&board, &Position { x: 3, y: 6 });
+
+        let expected_moves = vec![Position { x: 2, y: 6 }];
+
+        assert_eq!(legal_moves, expected_moves);
+    }
+
+    #[test]
+    fn test_pawn_on_last_rank_has_no_moves() {
+        let mut board = Board::init_empty();
+        board.board[0][3] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
+        board.board[7][3] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
+
+        assert!(Pawn::get_legal_moves_for_white(&board, &Position { x: 0, y: 3 }).is_empty());
+        assert!(Pawn::get_legal_moves_for_black(&board, &Position { x: 7, y: 3 }).is_empty());
+    }
 }
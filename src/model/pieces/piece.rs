@@ -4,8 +4,10 @@ use crate::model::Board;
 
 use super::{bishop::Bishop, king::King, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook};
 
+/// Pseudo-legal move generation for a piece standing on `from`. The targets
+/// ignore whether the move would leave the own king in check.
 pub trait Move {
-    fn available_moves(board: &Board) -> Option<Vec<Position>>;
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>>;
 }
 
 pub fn explore_moves(
@@ -49,6 +51,31 @@ pub struct Position {
     pub y: usize,
 }
 
+impl Position {
+    /// Parses a square in algebraic notation such as `e4`.
+    pub fn from_algebraic(square: &str) -> Option<Self> {
+        let mut chars = square.chars();
+        let file = chars.next()?;
+        let rank = chars.next()?;
+
+        if chars.next().is_some() || !('a'..='h').contains(&file) || !('1'..='8').contains(&rank) {
+            return None;
+        }
+
+        Some(Self {
+            x: (b'8' - rank as u8) as usize,
+            y: (file as u8 - b'a') as usize,
+        })
+    }
+}
+
+/// Algebraic notation of the square, e.g. `e4`.
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", (b'a' + self.y as u8) as char, 8 - self.x)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PieceType {
     Bishop,
@@ -60,14 +87,25 @@ pub enum PieceType {
 }
 
 impl PieceType {
-    pub fn legal_moves(&self, board: &Board) -> Option<Vec<Position>> {
+    /// Standard material value in pawns. The king is never traded, so it counts as 0.
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::Pawn => 1,
+            Self::Knight | Self::Bishop => 3,
+            Self::Rook => 5,
+            Self::Queen => 9,
+            Self::King => 0,
+        }
+    }
+
+    pub fn legal_moves(&self, board: &Board, from: &Position) -> Option<Vec<Position>> {
         match self {
-            Self::King => King::available_moves(board),
-            Self::Rook => Rook::available_moves(board),
-            Self::Pawn => Pawn::available_moves(board),
-            Self::Bishop => Bishop::available_moves(board),
-            Self::Knight => Knight::available_moves(board),
-            Self::Queen => Queen::available_moves(board),
+            Self::King => King::available_moves(board, from),
+            Self::Rook => Rook::available_moves(board, from),
+            Self::Pawn => Pawn::available_moves(board, from),
+            Self::Bishop => Bishop::available_moves(board, from),
+            Self::Knight => Knight::available_moves(board, from),
+            Self::Queen => Queen::available_moves(board, from),
         }
     }
 }
@@ -91,6 +129,15 @@ pub enum PieceColor {
     White,
 }
 
+impl PieceColor {
+    pub fn opposite(&self) -> Self {
+        match self {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White,
+        }
+    }
+}
+
 impl Display for PieceColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -100,7 +147,7 @@ impl Display for PieceColor {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Piece {
     pub piece_type: PieceType,
     pub piece_color: PieceColor,
@@ -113,6 +160,41 @@ impl Piece {
             piece_color,
         }
     }
+
+    /// Letter used in FEN: uppercase for white, lowercase for black.
+    pub fn fen_char(&self) -> char {
+        let letter = match self.piece_type {
+            PieceType::King => 'k',
+            PieceType::Queen => 'q',
+            PieceType::Rook => 'r',
+            PieceType::Bishop => 'b',
+            PieceType::Knight => 'n',
+            PieceType::Pawn => 'p',
+        };
+
+        match self.piece_color {
+            PieceColor::White => letter.to_ascii_uppercase(),
+            PieceColor::Black => letter,
+        }
+    }
+
+    /// Unicode chess symbol, outlined for white and filled for black.
+    pub fn unicode_symbol(&self) -> char {
+        match (self.piece_color, self.piece_type) {
+            (PieceColor::White, PieceType::King) => '♔',
+            (PieceColor::White, PieceType::Queen) => '♕',
+            (PieceColor::White, PieceType::Rook) => '♖',
+            (PieceColor::White, PieceType::Bishop) => '♗',
+            (PieceColor::White, PieceType::Knight) => '♘',
+            (PieceColor::White, PieceType::Pawn) => '♙',
+            (PieceColor::Black, PieceType::King) => '♚',
+            (PieceColor::Black, PieceType::Queen) => '♛',
+            (PieceColor::Black, PieceType::Rook) => '♜',
+            (PieceColor::Black, PieceType::Bishop) => '♝',
+            (PieceColor::Black, PieceType::Knight) => '♞',
+            (PieceColor::Black, PieceType::Pawn) => '♟',
+        }
+    }
 }
 
 impl Display for Piece {
@@ -3,22 +3,19 @@ use crate::model::{explore_moves, Board, Move, Position};
 pub struct Queen;
 
 impl Move for Queen {
-    fn available_moves(board: &Board) -> Option<Vec<Position>> {
-        let selected_position = board.selected_position.clone()?;
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>> {
         let mut legal_moves: Vec<Position> = Vec::with_capacity(27);
-        let color = board
-            .get_piece_color_in_position(selected_position.clone())
-            .unwrap();
+        let color = board.get_piece_color_in_position(from.clone())?;
 
         // Explore moves in all four directions using recursion
-        explore_moves(&selected_position, &mut legal_moves, board, color, 1, 0);
-        explore_moves(&selected_position, &mut legal_moves, board, color, -1, 0);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 0, 1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 0, -1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 1, 1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, -1, 1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, -1, -1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 1, -1);
+        explore_moves(from, &mut legal_moves, board, color, 1, 0);
+        explore_moves(from, &mut legal_moves, board, color, -1, 0);
+        explore_moves(from, &mut legal_moves, board, color, 0, 1);
+        explore_moves(from, &mut legal_moves, board, color, 0, -1);
+        explore_moves(from, &mut legal_moves, board, color, 1, 1);
+        explore_moves(from, &mut legal_moves, board, color, -1, 1);
+        explore_moves(from, &mut legal_moves, board, color, -1, -1);
+        explore_moves(from, &mut legal_moves, board, color, 1, -1);
 
         if legal_moves.is_empty() {
             None
@@ -36,10 +33,9 @@ mod tests {
 
     #[test]
     fn test_queen_initial_position() {
-        let mut board = Board::default();
-        board.select_position(&Position { x: 0, y: 0 });
+        let board = Board::default();
 
-        let legal_moves = Queen::available_moves(&board);
+        let legal_moves = Queen::available_moves(&board, &Position { x: 0, y: 0 });
 
         assert!(legal_moves.is_none());
     }
@@ -49,9 +45,8 @@ mod tests {
         let mut board = Board::init_empty();
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Queen, PieceColor::Black));
-        board.select_position(&Position { x: 5, y: 4 });
 
-        let legal_moves = Queen::available_moves(&board).unwrap();
+        let legal_moves = Queen::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 4, y: 4 },
@@ -94,9 +89,8 @@ mod tests {
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Queen, PieceColor::Black));
         board.board[3][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
-        board.select_position(&Position { x: 5, y: 4 });
 
-        let legal_moves = Queen::available_moves(&board).unwrap();
+        let legal_moves = Queen::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 5, y: 0 },
@@ -139,9 +133,8 @@ mod tests {
         board.board[3][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[5][1] = Some(Piece::new(PieceType::Knight, PieceColor::White));
         board.board[5][5] = Some(Piece::new(PieceType::Knight, PieceColor::White));
-        board.select_position(&Position { x: 5, y: 4 });
 
-        let legal_moves = Queen::available_moves(&board).unwrap();
+        let legal_moves = Queen::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 5, y: 1 },
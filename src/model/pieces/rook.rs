@@ -3,17 +3,14 @@ use crate::model::{explore_moves, Board, Move, Position};
 pub struct Rook;
 
 impl Move for Rook {
-    fn available_moves(board: &Board) -> Option<Vec<Position>> {
-        let selected_position = board.selected_position.clone()?;
+    fn available_moves(board: &Board, from: &Position) -> Option<Vec<Position>> {
         let mut legal_moves: Vec<Position> = Vec::with_capacity(14);
-        let color = board
-            .get_piece_color_in_position(selected_position.clone())
-            .unwrap();
+        let color = board.get_piece_color_in_position(from.clone())?;
 
-        explore_moves(&selected_position, &mut legal_moves, board, color, 1, 0);
-        explore_moves(&selected_position, &mut legal_moves, board, color, -1, 0);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 0, 1);
-        explore_moves(&selected_position, &mut legal_moves, board, color, 0, -1);
+        explore_moves(from, &mut legal_moves, board, color, 1, 0);
+        explore_moves(from, &mut legal_moves, board, color, -1, 0);
+        explore_moves(from, &mut legal_moves, board, color, 0, 1);
+        explore_moves(from, &mut legal_moves, board, color, 0, -1);
 
         if legal_moves.is_empty() {
             None
@@ -31,10 +28,9 @@ mod tests {
 
     #[test]
     fn test_rook_initial_position() {
-        let mut board = Board::default();
-        board.select_position(&Position { x: 0, y: 0 });
+        let board = Board::default();
 
-        let legal_moves = Rook::available_moves(&board);
+        let legal_moves = Rook::available_moves(&board, &Position { x: 0, y: 0 });
 
         assert!(legal_moves.is_none());
     }
@@ -44,9 +40,8 @@ mod tests {
         let mut board = Board::init_empty();
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Rook, PieceColor::Black));
-        board.select_position(&Position { x: 5, y: 4 });
 
-        let legal_moves = Rook::available_moves(&board).unwrap();
+        let legal_moves = Rook::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 4, y: 4 },
@@ -77,10 +72,9 @@ mod tests {
         let mut board = Board::init_empty();
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Rook, PieceColor::Black));
-        board.select_position(&Position { x: 5, y: 4 });
         board.board[3][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
 
-        let legal_moves = Rook::available_moves(&board).unwrap();
+        let legal_moves = Rook::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
         let expected_moves = vec![
             Position { x: 5, y: 0 },
@@ -106,15 +100,14 @@ mod tests {
         let mut board = Board::init_empty();
         board.on_turn = PieceColor::Black;
         board.board[5][4] = Some(Piece::new(PieceType::Rook, PieceColor::Black));
-        board.select_position(&Position { x: 5, y: 4 });
         board.board[6][4] = Some(Piece::new(PieceType::Pawn, PieceColor::Black));
         board.board[3][4] = Some(Piece::new(PieceType::Pawn, PieceColor::White));
         board.board[5][1] = Some(Piece::new(PieceType::Knight, PieceColor::White));
         board.board[5][5] = Some(Piece::new(PieceType::Knight, PieceColor::White));
 
-        let legal_moves = Rook::available_moves(&board).unwrap();
+        let legal_moves = Rook::available_moves(&board, &Position { x: 5, y: 4 }).unwrap();
 
-        let expected_moves = vec![
+        let expected_moves = [
             Position { x: 5, y: 1 },
             Position { x: 5, y: 2 },
             Position { x: 5, y: 3 },
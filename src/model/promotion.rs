@@ -0,0 +1,41 @@
+use super::{PieceColor, PieceType, Position};
+
+pub const PROMOTION_PIECES: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
+#[derive(Debug, Clone)]
+pub struct PromotionState {
+    pub target: Position,
+    pub color: PieceColor,
+    pub selected: usize,
+}
+
+impl PromotionState {
+    pub fn new(target: Position, color: PieceColor) -> Self {
+        Self {
+            target,
+            color,
+            selected: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % PROMOTION_PIECES.len();
+    }
+
+    pub fn previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        } else {
+            self.selected = PROMOTION_PIECES.len() - 1;
+        }
+    }
+
+    pub fn selected_piece(&self) -> PieceType {
+        PROMOTION_PIECES[self.selected]
+    }
+}
@@ -0,0 +1,142 @@
+use super::{
+    pgn::{PgnError, PgnGame},
+    Board, ChessMove,
+};
+
+impl PgnGame {
+    /// Short description for the game list, e.g. `Alice - Bob (1-0)`.
+    pub fn title(&self) -> String {
+        format!(
+            "{} - {} ({})",
+            self.tag("White").unwrap_or("?"),
+            self.tag("Black").unwrap_or("?"),
+            self.result.as_deref().or(self.tag("Result")).unwrap_or("*")
+        )
+    }
+}
+
+/// Games read from a PGN file and the one highlighted in the list.
+#[derive(Debug, Clone, Default)]
+pub struct GameListState {
+    pub games: Vec<PgnGame>,
+    pub selected: usize,
+}
+
+impl GameListState {
+    pub fn new(games: Vec<PgnGame>) -> Self {
+        Self { games, selected: 0 }
+    }
+
+    pub fn next(&mut self) {
+        if !self.games.is_empty() {
+            self.selected = (self.selected + 1) % self.games.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        } else {
+            self.selected = self.games.len().saturating_sub(1);
+        }
+    }
+
+    pub fn selected_game(&self) -> Option<&PgnGame> {
+        self.games.get(self.selected)
+    }
+}
+
+/// Stepping through a loaded game. The board shows the position after `ply` moves;
+/// moving forward makes the next move and moving back unmakes it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayState {
+    pub title: String,
+    pub moves: Vec<ChessMove>,
+    pub ply: usize,
+}
+
+impl ReplayState {
+    /// Resolves the moves of `game` and returns the replay with its starting board.
+    pub fn new(game: &PgnGame) -> Result<(Self, Board), PgnError> {
+        let moves = game.chess_moves()?;
+        let board = game.starting_board()?;
+        let replay = Self {
+            title: game.title(),
+            moves,
+            ply: 0,
+        };
+
+        Ok((replay, board))
+    }
+
+    pub fn forward(&mut self, board: &mut Board) -> bool {
+        let Some(chess_move) = self.moves.get(self.ply) else {
+            return false;
+        };
+
+        board.make_move(chess_move);
+        self.ply += 1;
+        true
+    }
+
+    pub fn back(&mut self, board: &mut Board) -> bool {
+        if self.ply == 0 || board.unmake_move().is_none() {
+            return false;
+        }
+
+        self.ply -= 1;
+        true
+    }
+
+    pub fn to_start(&mut self, board: &mut Board) {
+        while self.back(board) {}
+    }
+
+    pub fn to_end(&mut self, board: &mut Board) {
+        while self.forward(board) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::pgn::parse_pgn;
+
+    #[test]
+    fn test_step_through_game() {
+        let games = parse_pgn("[White \"Alice\"]\n\n1. f3 e5 2. g4 Qh4# 0-1");
+        let (mut replay, mut board) = ReplayState::new(&games[0]).unwrap();
+        let start = board.clone();
+
+        assert_eq!(replay.title, "Alice - ? (0-1)");
+        assert!(!replay.back(&mut board));
+
+        assert!(replay.forward(&mut board));
+        assert!(replay.forward(&mut board));
+        assert_eq!(replay.ply, 2);
+
+        replay.to_end(&mut board);
+        assert_eq!(replay.ply, 4);
+        assert!(board.result.is_over());
+        assert!(!replay.forward(&mut board));
+
+        assert!(replay.back(&mut board));
+        assert!(!board.result.is_over());
+
+        replay.to_start(&mut board);
+        assert_eq!(replay.ply, 0);
+        assert_eq!(board, start);
+    }
+
+    #[test]
+    fn test_game_list_wraps() {
+        let mut list = GameListState::new(parse_pgn("1. e4 * 1. d4 * 1. c4 *"));
+
+        assert_eq!(list.games.len(), 3);
+        list.previous();
+        assert_eq!(list.selected, 2);
+        list.next();
+        assert_eq!(list.selected, 0);
+        assert_eq!(list.selected_game().unwrap().moves, ["e4"]);
+    }
+}
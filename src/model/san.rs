@@ -0,0 +1,578 @@
+use std::{error::Error, fmt::Display};
+
+use super::{Board, CastlingSide, ChessMove, GameResult, MoveKind, PieceType, Position, WinReason};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SanError {
+    Malformed(String),
+    Illegal(String),
+    Ambiguous {
+        san: String,
+        candidates: Vec<String>,
+    },
+    MissingPromotion(String),
+}
+
+impl Display for SanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SanError::Malformed(san) => write!(f, "'{}' is not a move in algebraic notation", san),
+            SanError::Illegal(san) => write!(f, "{} is not a legal move here", san),
+            SanError::Ambiguous { san, candidates } => write!(
+                f,
+                "{} is ambiguous, it could be {}",
+                san,
+                candidates.join(" or ")
+            ),
+            SanError::MissingPromotion(san) => {
+                write!(f, "{} needs a promotion piece, e.g. {}=Q", san, san)
+            }
+        }
+    }
+}
+
+impl Error for SanError {}
+
+/// What a SAN string says about a move, before looking at the position.
+struct SanPattern {
+    castling: Option<CastlingSide>,
+    piece_type: PieceType,
+    from_file: Option<usize>,
+    from_rank: Option<usize>,
+    is_capture: bool,
+    to: Position,
+    promotion: Option<PieceType>,
+}
+
+fn parse_piece_letter(c: char) -> Option<PieceType> {
+    match c {
+        'K' => Some(PieceType::King),
+        'Q' => Some(PieceType::Queen),
+        'R' => Some(PieceType::Rook),
+        'B' => Some(PieceType::Bishop),
+        'N' => Some(PieceType::Knight),
+        _ => None,
+    }
+}
+
+fn parse_file(c: char) -> Option<usize> {
+    ('a'..='h').contains(&c).then(|| c as usize - 'a' as usize)
+}
+
+/// Row of a rank digit, counted from rank 8 like `Position::x`.
+fn parse_rank(c: char) -> Option<usize> {
+    ('1'..='8').contains(&c).then(|| '8' as usize - c as usize)
+}
+
+/// Reads `[piece][file][rank][x]square[=promotion]`, tolerating a missing `=`,
+/// a lowercase promotion letter and zeros in castling.
+fn parse_pattern(san: &str) -> Option<SanPattern> {
+    let castling = match san {
+        "O-O" | "0-0" => Some(CastlingSide::KingSide),
+        "O-O-O" | "0-0-0" => Some(CastlingSide::QueenSide),
+        _ => None,
+    };
+    if castling.is_some() {
+        return Some(SanPattern {
+            castling,
+            piece_type: PieceType::King,
+            from_file: None,
+            from_rank: None,
+            is_capture: false,
+            to: Position { x: 0, y: 0 },
+            promotion: None,
+        });
+    }
+
+    let mut chars: Vec<char> = san.chars().collect();
+
+    let piece_type = match chars.first().copied().and_then(parse_piece_letter) {
+        Some(piece_type) => {
+            chars.remove(0);
+            piece_type
+        }
+        None => PieceType::Pawn,
+    };
+
+    let mut promotion = None;
+    if piece_type == PieceType::Pawn {
+        if let Some(promotion_type) = chars
+            .last()
+            .and_then(|c| parse_piece_letter(c.to_ascii_uppercase()))
+            .filter(|promotion_type| *promotion_type != PieceType::King)
+        {
+            chars.pop();
+            if chars.last() == Some(&'=') {
+                chars.pop();
+            }
+            promotion = Some(promotion_type);
+        }
+    }
+
+    if chars.len() < 2 {
+        return None;
+    }
+    let rank = chars.pop().and_then(parse_rank)?;
+    let file = chars.pop().and_then(parse_file)?;
+
+    let is_capture = matches!(chars.last(), Some('x' | ':'));
+    if is_capture {
+        chars.pop();
+    }
+
+    let (from_file, from_rank) = match chars.as_slice() {
+        [] => (None, None),
+        [c] if parse_file(*c).is_some() => (parse_file(*c), None),
+        [c] if parse_rank(*c).is_some() => (None, parse_rank(*c)),
+        [f, r] => (Some(parse_file(*f)?), Some(parse_rank(*r)?)),
+        _ => return None,
+    };
+
+    Some(SanPattern {
+        castling: None,
+        piece_type,
+        from_file,
+        from_rank,
+        is_capture,
+        to: Position { x: rank, y: file },
+        promotion,
+    })
+}
+
+fn piece_letter(piece_type: PieceType) -> String {
+    match piece_type {
+        PieceType::Pawn => String::new(),
+        _ => piece_type.to_string(),
+    }
+}
+
+impl Board {
+    /// Standard Algebraic Notation of a legal move in the current position, e.g.
+    /// `Nbd7`, `exd6`, `e8=Q+` or `O-O-O#`.
+    pub fn san(&self, chess_move: &ChessMove) -> String {
+        let Some(piece) = self.board[chess_move.from.x][chess_move.from.y] else {
+            return chess_move.to_string();
+        };
+
+        let mut san = match chess_move.kind {
+            MoveKind::Castling(CastlingSide::KingSide) => String::from("O-O"),
+            MoveKind::Castling(CastlingSide::QueenSide) => String::from("O-O-O"),
+            _ => {
+                let mut san = piece_letter(piece.piece_type);
+
+                if piece.piece_type == PieceType::Pawn {
+                    if chess_move.is_capture() {
+                        san.push(file_char(chess_move.from.y));
+                    }
+                } else {
+                    san.push_str(&self.disambiguation(chess_move, piece.piece_type));
+                }
+
+                if chess_move.is_capture() {
+                    san.push('x');
+                }
+                san.push_str(&chess_move.to.to_string());
+
+                if let Some(promotion) = chess_move.promotion {
+                    san.push('=');
+                    san.push_str(&piece_letter(promotion));
+                }
+                san
+            }
+        };
+
+        let mut next = self.clone();
+        next.make_move(chess_move);
+        if next.result == GameResult::win_for(piece.piece_color, WinReason::Checkmate) {
+            san.push('#');
+        } else if next.is_check {
+            san.push('+');
+        }
+
+        san
+    }
+
+    /// Resolves a move in Standard Algebraic Notation, such as `Nbd7` or `exd8=Q+`,
+    /// against the legal moves of the current position.
+    pub fn parse_san(&self, san: &str) -> Result<ChessMove, SanError> {
+        let san = san.trim();
+        let stripped = san.trim_end_matches(['+', '#', '!', '?']);
+        let pattern =
+            parse_pattern(stripped).ok_or_else(|| SanError::Malformed(san.to_string()))?;
+
+        let candidates: Vec<ChessMove> = self
+            .legal_moves()
+            .into_iter()
+            .filter(|chess_move| match pattern.castling {
+                Some(side) => chess_move.kind == MoveKind::Castling(side),
+                None => {
+                    !matches!(chess_move.kind, MoveKind::Castling(_))
+                        && chess_move.to == pattern.to
+                        && matches!(self.board[chess_move.from.x][chess_move.from.y],
+                            Some(piece) if piece.piece_type == pattern.piece_type)
+                        && pattern.from_file.is_none_or(|y| chess_move.from.y == y)
+                        && pattern.from_rank.is_none_or(|x| chess_move.from.x == x)
+                        && (!pattern.is_capture || chess_move.is_capture())
+                        // A pawn move written without `x` is a push along its file
+                        && (pattern.piece_type != PieceType::Pawn
+                            || pattern.is_capture
+                            || chess_move.from.y == pattern.to.y)
+                }
+            })
+            .collect();
+
+        let is_promotion = candidates
+            .iter()
+            .any(|chess_move| chess_move.promotion.is_some());
+        if is_promotion && pattern.promotion.is_none() {
+            return Err(SanError::MissingPromotion(stripped.to_string()));
+        }
+
+        let mut candidates: Vec<ChessMove> = candidates
+            .into_iter()
+            .filter(|chess_move| chess_move.promotion == pattern.promotion)
+            .collect();
+
+        match candidates.len() {
+            0 => Err(SanError::Illegal(san.to_string())),
+            1 => Ok(candidates.remove(0)),
+            _ => Err(SanError::Ambiguous {
+                san: san.to_string(),
+                candidates: candidates
+                    .iter()
+                    .map(|chess_move| self.san(chess_move))
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Resolves typed input in either coordinate notation (`e2e4`, `e7e8q`) or SAN.
+    pub fn parse_move(&self, input: &str) -> Result<ChessMove, SanError> {
+        let input = input.trim();
+        let chars: Vec<char> = input.chars().collect();
+
+        let coordinates = match chars.as_slice() {
+            [from_file, from_rank, to_file, to_rank, rest @ ..] if rest.len() <= 1 => {
+                let position = |file: char, rank: char| {
+                    Some(Position {
+                        x: parse_rank(rank)?,
+                        y: parse_file(file)?,
+                    })
+                };
+                let promotion = match rest {
+                    [c] => match parse_piece_letter(c.to_ascii_uppercase()) {
+                        Some(PieceType::King) | None => None,
+                        promotion => Some(promotion),
+                    },
+                    _ => Some(None),
+                };
+                position(*from_file, *from_rank)
+                    .zip(position(*to_file, *to_rank))
+                    .zip(promotion)
+            }
+            _ => None,
+        };
+
+        let Some(((from, to), promotion)) = coordinates else {
+            return self.parse_san(input);
+        };
+
+        let moves: Vec<ChessMove> = self
+            .moves_from(&from)
+            .into_iter()
+            .filter(|chess_move| chess_move.to == to)
+            .collect();
+        if promotion.is_none()
+            && moves
+                .iter()
+                .any(|chess_move| chess_move.promotion.is_some())
+        {
+            return Err(SanError::MissingPromotion(input.to_string()));
+        }
+
+        moves
+            .into_iter()
+            .find(|chess_move| chess_move.promotion == promotion)
+            .ok_or_else(|| SanError::Illegal(input.to_string()))
+    }
+
+    /// Legal moves in SAN and coordinate notation that start with `prefix`, sorted.
+    pub fn move_completions(&self, prefix: &str) -> Vec<String> {
+        let mut completions: Vec<String> = self
+            .legal_moves()
+            .iter()
+            .flat_map(|chess_move| [self.san(chess_move), chess_move.to_string()])
+            .filter(|notation| notation.starts_with(prefix))
+            .collect();
+
+        completions.sort();
+        completions.dedup();
+        completions
+    }
+
+    /// File, rank or full square of the origin, when other pieces of the same type
+    /// could also reach the target.
+    fn disambiguation(&self, chess_move: &ChessMove, piece_type: PieceType) -> String {
+        let rivals: Vec<ChessMove> = self
+            .legal_moves()
+            .into_iter()
+            .filter(|other| {
+                other.to == chess_move.to
+                    && other.from != chess_move.from
+                    && matches!(self.board[other.from.x][other.from.y],
+                        Some(piece) if piece.piece_type == piece_type)
+            })
+            .collect();
+
+        if rivals.is_empty() {
+            String::new()
+        } else if rivals.iter().all(|other| other.from.y != chess_move.from.y) {
+            file_char(chess_move.from.y).to_string()
+        } else if rivals.iter().all(|other| other.from.x != chess_move.from.x) {
+            rank_char(chess_move.from.x).to_string()
+        } else {
+            chess_move.from.to_string()
+        }
+    }
+}
+
+fn file_char(y: usize) -> char {
+    (b'a' + y as u8) as char
+}
+
+fn rank_char(x: usize) -> char {
+    (b'8' - x as u8) as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn san_of(fen: &str, coordinates: &str) -> String {
+        let board = Board::from_fen(fen).unwrap();
+        let chess_move = board
+            .legal_moves()
+            .into_iter()
+            .find(|chess_move| chess_move.to_string() == coordinates)
+            .unwrap();
+        board.san(&chess_move)
+    }
+
+    #[test]
+    fn test_san_of_simple_moves() {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+        assert_eq!(san_of(fen, "e2e4"), "e4");
+        assert_eq!(san_of(fen, "g1f3"), "Nf3");
+    }
+
+    #[test]
+    fn test_san_of_captures_and_en_passant() {
+        let fen = "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3";
+
+        assert_eq!(san_of(fen, "e5f6"), "exf6");
+        assert_eq!(
+            san_of(
+                "r1bqkbnr/pppp1ppp/2n5/4p3/3PP3/5N2/PPP2PPP/RNBQKB1R b KQkq - 0 3",
+                "e5d4"
+            ),
+            "exd4"
+        );
+        assert_eq!(
+            san_of(
+                "r1bqkbnr/pppp1ppp/2n5/8/3pP3/5N2/PPP2PPP/RNBQKB1R w KQkq - 0 4",
+                "f3d4"
+            ),
+            "Nxd4"
+        );
+    }
+
+    #[test]
+    fn test_san_disambiguation() {
+        // Knights on b1 and f3 both reach d2, rooks on a1 and a5 both reach a3
+        let fen = "4k3/8/8/R7/8/8/8/RN2K3 w - - 0 1";
+        assert_eq!(san_of(fen, "a1a3"), "R1a3");
+        assert_eq!(san_of(fen, "a5a3"), "R5a3");
+
+        let fen = "4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1";
+        assert_eq!(san_of(fen, "b1d2"), "Nbd2");
+
+        // Queens on h4, h1 and e1 all reach e4
+        let fen = "2k5/8/8/8/7Q/8/K7/4Q2Q w - - 0 1";
+        assert_eq!(san_of(fen, "h4e4"), "Q4e4");
+        assert_eq!(san_of(fen, "e1e4"), "Qee4");
+        assert_eq!(san_of(fen, "h1e4"), "Qh1e4");
+    }
+
+    #[test]
+    fn test_san_of_castling_promotion_and_checks() {
+        assert_eq!(
+            san_of("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "e1g1"),
+            "O-O"
+        );
+        assert_eq!(san_of("r3k3/8/8/8/8/8/8/3K4 b q - 0 1", "e8c8"), "O-O-O+");
+        assert_eq!(san_of("3k4/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8q"), "b8=Q+");
+        assert_eq!(san_of("3k4/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8n"), "b8=N");
+        assert_eq!(
+            san_of(
+                "rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - 0 2",
+                "d8h4"
+            ),
+            "Qh4#"
+        );
+    }
+
+    fn parse(fen: &str, san: &str) -> Result<String, SanError> {
+        let board = Board::from_fen(fen).unwrap();
+        board
+            .parse_san(san)
+            .map(|chess_move| chess_move.to_string())
+    }
+
+    #[test]
+    fn test_parse_simple_moves() {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+        assert_eq!(parse(fen, "e4"), Ok(String::from("e2e4")));
+        assert_eq!(parse(fen, "e3"), Ok(String::from("e2e3")));
+        assert_eq!(parse(fen, "Nf3"), Ok(String::from("g1f3")));
+        assert_eq!(parse(fen, "Ngf3"), Ok(String::from("g1f3")));
+        assert_eq!(parse(fen, "Ng1f3!?"), Ok(String::from("g1f3")));
+    }
+
+    #[test]
+    fn test_parse_captures_and_promotions() {
+        let fen = "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3";
+        assert_eq!(parse(fen, "exf6"), Ok(String::from("e5f6")));
+        assert_eq!(
+            parse(fen, "exf6e.p."),
+            Err(SanError::Malformed(String::from("exf6e.p.")))
+        );
+
+        let fen = "r2qk3/1P6/8/8/8/8/8/4K3 w q - 0 1";
+        assert_eq!(parse(fen, "bxa8=Q+"), Ok(String::from("b7a8q")));
+        assert_eq!(parse(fen, "bxa8N"), Ok(String::from("b7a8n")));
+        assert_eq!(parse(fen, "b8=r"), Ok(String::from("b7b8r")));
+        assert_eq!(
+            parse(fen, "b8"),
+            Err(SanError::MissingPromotion(String::from("b8")))
+        );
+        assert_eq!(
+            parse(fen, "b8=K"),
+            Err(SanError::Malformed(String::from("b8=K")))
+        );
+    }
+
+    #[test]
+    fn test_parse_castling() {
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+
+        assert_eq!(parse(fen, "O-O"), Ok(String::from("e1g1")));
+        assert_eq!(parse(fen, "0-0-0"), Ok(String::from("e1c1")));
+        assert_eq!(
+            parse("r3k2r/8/8/8/8/8/8/R3K2R w - - 0 1", "O-O"),
+            Err(SanError::Illegal(String::from("O-O")))
+        );
+    }
+
+    #[test]
+    fn test_parse_ambiguous_and_illegal() {
+        let fen = "4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1";
+
+        let error = parse(fen, "Nd2").unwrap_err();
+        assert_eq!(
+            error,
+            SanError::Ambiguous {
+                san: String::from("Nd2"),
+                candidates: vec![String::from("Nfd2"), String::from("Nbd2")]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Nd2 is ambiguous, it could be Nfd2 or Nbd2"
+        );
+
+        assert_eq!(parse(fen, "Nbd2"), Ok(String::from("b1d2")));
+        assert_eq!(
+            parse(fen, "Nxd2"),
+            Err(SanError::Illegal(String::from("Nxd2")))
+        );
+        assert_eq!(
+            parse(fen, "Qd2"),
+            Err(SanError::Illegal(String::from("Qd2")))
+        );
+        assert_eq!(
+            parse(fen, "Nz9"),
+            Err(SanError::Malformed(String::from("Nz9")))
+        );
+        assert_eq!(parse(fen, ""), Err(SanError::Malformed(String::new())));
+    }
+
+    #[test]
+    fn test_pawn_push_is_not_a_capture() {
+        // One white pawn can take on d5, but nothing can push there
+        let one_capturer = "4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1";
+        assert_eq!(
+            parse(one_capturer, "d5"),
+            Err(SanError::Illegal(String::from("d5")))
+        );
+        assert_eq!(parse(one_capturer, "exd5"), Ok(String::from("e4d5")));
+
+        // Two pawns can take on d5, which still does not make `d5` ambiguous
+        let two_capturers = "4k3/8/8/3p4/2P1P3/8/8/4K3 w - - 0 1";
+        assert_eq!(
+            parse(two_capturers, "d5"),
+            Err(SanError::Illegal(String::from("d5")))
+        );
+        assert_eq!(parse(two_capturers, "cxd5"), Ok(String::from("c4d5")));
+    }
+
+    #[test]
+    fn test_parse_coordinate_notation() {
+        let board = Board::default();
+        assert_eq!(
+            board.parse_move("e2e4").map(|m| m.to_string()),
+            Ok(String::from("e2e4"))
+        );
+        assert_eq!(
+            board.parse_move("Nc3").map(|m| m.to_string()),
+            Ok(String::from("b1c3"))
+        );
+        assert_eq!(
+            board.parse_move("e2e5"),
+            Err(SanError::Illegal(String::from("e2e5")))
+        );
+
+        let board = Board::from_fen("3k4/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(
+            board.parse_move("b7b8n").map(|m| m.to_string()),
+            Ok(String::from("b7b8n"))
+        );
+        assert_eq!(
+            board.parse_move("b7b8"),
+            Err(SanError::MissingPromotion(String::from("b7b8")))
+        );
+    }
+
+    #[test]
+    fn test_move_completions() {
+        let board = Board::default();
+
+        assert_eq!(board.move_completions("N"), ["Na3", "Nc3", "Nf3", "Nh3"]);
+        assert_eq!(board.move_completions("e"), ["e2e3", "e2e4", "e3", "e4"]);
+        assert_eq!(board.move_completions("g1"), ["g1f3", "g1h3"]);
+        assert!(board.move_completions("K").is_empty());
+        assert_eq!(board.move_completions("").len(), 40);
+    }
+
+    #[test]
+    fn test_generated_san_parses_back() {
+        let board =
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+
+        for chess_move in board.legal_moves() {
+            assert_eq!(board.parse_san(&board.san(&chess_move)), Ok(chess_move));
+        }
+    }
+}
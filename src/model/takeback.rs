@@ -0,0 +1,156 @@
+use super::{clock::ChessClock, Board, ChessMove, PieceColor};
+
+/// Moves that can be taken back and replayed, with the clocks as they stood around them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UndoStack {
+    /// Clock at the moment each move of the game was made, one entry per ply.
+    clocks: Vec<Option<ChessClock>>,
+    /// Moves taken back, most recent last, with the clock from before taking them back.
+    redo: Vec<(ChessMove, Option<ChessClock>)>,
+}
+
+impl UndoStack {
+    /// Remembers the clock for a move that was just made. A new move replaces any
+    /// moves that were taken back.
+    pub fn record(&mut self, clock: Option<ChessClock>) {
+        self.clocks.push(clock);
+        self.redo.clear();
+    }
+
+    /// Unmakes the last move and turns the clock back to when it was made.
+    pub fn undo(&mut self, board: &mut Board, clock: &mut Option<ChessClock>) -> bool {
+        let current_clock = clock.clone();
+        let Some(chess_move) = board.unmake_move() else {
+            return false;
+        };
+
+        if let Some(move_clock) = self.clocks.pop().flatten() {
+            *clock = Some(move_clock);
+        }
+        self.redo.push((chess_move, current_clock));
+        true
+    }
+
+    /// Makes the last move taken back again, with the clock it had then.
+    pub fn redo(&mut self, board: &mut Board, clock: &mut Option<ChessClock>) -> bool {
+        let Some((chess_move, redo_clock)) = self.redo.pop() else {
+            return false;
+        };
+
+        self.clocks.push(clock.clone());
+        board.make_move(&chess_move);
+        *clock = redo_clock;
+        true
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+/// A player asking to take back their last move, waiting for the opponent's answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TakebackRequest {
+    pub requested_by: PieceColor,
+}
+
+impl TakebackRequest {
+    /// Plies to unmake so that the requesting side is to move again: just their move,
+    /// or also the reply if the opponent already made one. 0 if they have not moved.
+    pub fn plies(&self, board: &Board) -> usize {
+        let plies = if board.on_turn == self.requested_by {
+            2
+        } else {
+            1
+        };
+
+        if board.move_history().len() < plies {
+            0
+        } else {
+            plies
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(board: &mut Board, undo_stack: &mut UndoStack, san: &str) {
+        let chess_move = board.parse_san(san).unwrap();
+        undo_stack.record(None);
+        board.make_move(&chess_move);
+    }
+
+    #[test]
+    fn test_undo_restores_exact_board() {
+        let mut board = Board::default();
+        let mut undo_stack = UndoStack::default();
+        let mut clock = None;
+        play(&mut board, &mut undo_stack, "e4");
+        play(&mut board, &mut undo_stack, "d5");
+        let before_capture = board.clone();
+        play(&mut board, &mut undo_stack, "exd5");
+
+        assert!(undo_stack.undo(&mut board, &mut clock));
+        assert_eq!(board, before_capture);
+        assert_eq!(board.to_fen(), before_capture.to_fen());
+        assert!(board.captured_pieces().is_empty());
+
+        assert!(undo_stack.redo(&mut board, &mut clock));
+        assert_eq!(board.captured_pieces().len(), 1);
+        assert!(!undo_stack.can_redo());
+    }
+
+    #[test]
+    fn test_new_move_drops_redo() {
+        let mut board = Board::default();
+        let mut undo_stack = UndoStack::default();
+        let mut clock = None;
+        play(&mut board, &mut undo_stack, "e4");
+        undo_stack.undo(&mut board, &mut clock);
+        assert!(undo_stack.can_redo());
+
+        play(&mut board, &mut undo_stack, "d4");
+        assert!(!undo_stack.redo(&mut board, &mut clock));
+        assert!(undo_stack.undo(&mut board, &mut clock));
+        assert!(!undo_stack.undo(&mut board, &mut clock));
+    }
+
+    #[test]
+    fn test_undo_turns_clock_back() {
+        let mut board = Board::default();
+        let mut undo_stack = UndoStack::default();
+        let mut clock = Some(ChessClock::new("1+5".parse().unwrap()));
+
+        undo_stack.record(clock.clone());
+        board.make_move(&board.parse_san("e4").unwrap());
+        clock.as_mut().unwrap().press(PieceColor::White);
+        let after_move = clock.clone();
+
+        undo_stack.undo(&mut board, &mut clock);
+        assert_eq!(clock.as_ref().unwrap().running, None);
+        assert_eq!(clock.as_ref().unwrap().white.remaining.as_secs(), 60);
+
+        undo_stack.redo(&mut board, &mut clock);
+        assert_eq!(clock, after_move);
+    }
+
+    #[test]
+    fn test_takeback_plies() {
+        let mut board = Board::default();
+        let request = TakebackRequest {
+            requested_by: PieceColor::White,
+        };
+        assert_eq!(request.plies(&board), 0);
+
+        board.make_move(&board.parse_san("e4").unwrap());
+        assert_eq!(request.plies(&board), 1);
+        let black_request = TakebackRequest {
+            requested_by: PieceColor::Black,
+        };
+        assert_eq!(black_request.plies(&board), 0);
+        board.make_move(&board.parse_san("e5").unwrap());
+        assert_eq!(request.plies(&board), 2);
+    }
+}
@@ -0,0 +1,106 @@
+/// Single line of editable text with a cursor, counted in characters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextInput {
+    pub value: String,
+    pub cursor: usize,
+}
+
+impl TextInput {
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            cursor: value.chars().count(),
+        }
+    }
+
+    fn byte_index(&self) -> usize {
+        self.value
+            .char_indices()
+            .nth(self.cursor)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+
+    pub fn insert(&mut self, c: char) {
+        let index = self.byte_index();
+        self.value.insert(index, c);
+        self.cursor += 1;
+    }
+
+    /// Inserts pasted text, dropping line breaks since the input is a single line.
+    pub fn insert_str(&mut self, text: &str) {
+        text.chars()
+            .filter(|c| *c != '\n' && *c != '\r')
+            .for_each(|c| self.insert(c));
+    }
+
+    /// Removes the character before the cursor, like backspace.
+    pub fn delete_previous(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let index = self.byte_index();
+            self.value.remove(index);
+        }
+    }
+
+    /// Removes the character under the cursor, like delete.
+    pub fn delete_next(&mut self) {
+        if self.cursor < self.value.chars().count() {
+            let index = self.byte_index();
+            self.value.remove(index);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.value.chars().count());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.value.chars().count();
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editing_at_cursor() {
+        let mut input = TextInput::new("e4");
+        input.move_home();
+        input.insert('N');
+        input.move_end();
+        input.insert('+');
+        assert_eq!(input.value, "Ne4+");
+
+        input.move_left();
+        input.delete_previous();
+        input.delete_next();
+        assert_eq!(input.value, "Ne");
+        assert_eq!(input.cursor, 2);
+
+        input.move_right();
+        assert_eq!(input.cursor, 2);
+    }
+
+    #[test]
+    fn test_paste_drops_line_breaks() {
+        let mut input = TextInput::default();
+        input.insert_str("8/8/8/8/8/8/8/K6k\r\n w - - 0 1\n");
+
+        assert_eq!(input.value, "8/8/8/8/8/8/8/K6k w - - 0 1");
+        assert_eq!(input.cursor, input.value.len());
+    }
+}
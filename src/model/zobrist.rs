@@ -0,0 +1,94 @@
+use std::sync::OnceLock;
+
+use super::{CastlingRights, Piece, PieceColor, PieceType, Position};
+
+struct ZobristKeys {
+    pieces: [[[u64; 64]; 6]; 2],
+    black_to_move: u64,
+    castling_rights: [u64; 4],
+    en_passant_file: [u64; 8],
+}
+
+/// SplitMix64 with a fixed seed, so keys are identical across runs.
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl ZobristKeys {
+    fn new() -> Self {
+        let mut state = 0x0C4E_5573_7E72_4D00;
+        let mut keys = Self {
+            pieces: [[[0; 64]; 6]; 2],
+            black_to_move: 0,
+            castling_rights: [0; 4],
+            en_passant_file: [0; 8],
+        };
+
+        keys.pieces
+            .iter_mut()
+            .flatten()
+            .flatten()
+            .for_each(|key| *key = next_random(&mut state));
+        keys.black_to_move = next_random(&mut state);
+        keys.castling_rights
+            .iter_mut()
+            .for_each(|key| *key = next_random(&mut state));
+        keys.en_passant_file
+            .iter_mut()
+            .for_each(|key| *key = next_random(&mut state));
+
+        keys
+    }
+}
+
+fn keys() -> &'static ZobristKeys {
+    static KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+    KEYS.get_or_init(ZobristKeys::new)
+}
+
+pub fn piece_key(piece: &Piece, position: &Position) -> u64 {
+    let color = match piece.piece_color {
+        PieceColor::White => 0,
+        PieceColor::Black => 1,
+    };
+    let piece_type = match piece.piece_type {
+        PieceType::Pawn => 0,
+        PieceType::Knight => 1,
+        PieceType::Bishop => 2,
+        PieceType::Rook => 3,
+        PieceType::Queen => 4,
+        PieceType::King => 5,
+    };
+
+    keys().pieces[color][piece_type][position.x * 8 + position.y]
+}
+
+pub fn side_to_move_key(color: PieceColor) -> u64 {
+    match color {
+        PieceColor::White => 0,
+        PieceColor::Black => keys().black_to_move,
+    }
+}
+
+pub fn castling_rights_key(castling_rights: &CastlingRights) -> u64 {
+    [
+        castling_rights.white_king_side,
+        castling_rights.white_queen_side,
+        castling_rights.black_king_side,
+        castling_rights.black_queen_side,
+    ]
+    .iter()
+    .zip(keys().castling_rights)
+    .filter(|(has_right, _)| **has_right)
+    .fold(0, |key, (_, right_key)| key ^ right_key)
+}
+
+/// Only hashed when an en passant capture is actually available, so positions
+/// that merely follow a double pawn move still match their transpositions.
+pub fn en_passant_key(file: Option<usize>) -> u64 {
+    file.map_or(0, |file| keys().en_passant_file[file])
+}
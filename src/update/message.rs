@@ -1,4 +1,6 @@
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
 use std::{
     sync::mpsc,
     thread,
@@ -7,15 +9,20 @@ use std::{
 
 use crate::{
     game::{AppResult, CurrentScreen, Game},
-    model::Direction,
+    model::{text_input::TextInput, Direction},
+    view::board::square_at,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Message {
     Tick,
     Quit,
     KeyPress(KeyEvent),
     MousePress(MouseEvent),
+    Resize(u16, u16),
+    FocusGained,
+    FocusLost,
+    Paste(String),
 }
 
 #[allow(dead_code)]
@@ -43,7 +50,12 @@ impl MessageHandler {
                         match event::read().expect("Unable to read event") {
                             Event::Key(e) => sender.send(Message::KeyPress(e)),
                             Event::Mouse(e) => sender.send(Message::MousePress(e)),
-                            _ => unimplemented!(),
+                            Event::Resize(width, height) => {
+                                sender.send(Message::Resize(width, height))
+                            }
+                            Event::FocusGained => sender.send(Message::FocusGained),
+                            Event::FocusLost => sender.send(Message::FocusLost),
+                            Event::Paste(text) => sender.send(Message::Paste(text)),
                         }
                         .expect("Failed to send terminal event")
                     }
@@ -77,18 +89,130 @@ impl MessageHandler {
         match game.current_screen {
             CurrentScreen::Menu => self.handle_menu_key_events(key_event, game),
             CurrentScreen::Game => self.handle_game_key_events(key_event, game),
+            CurrentScreen::FenInput => self.handle_fen_input_key_events(key_event, game),
+            CurrentScreen::PgnInput => self.handle_pgn_input_key_events(key_event, game),
+            CurrentScreen::GameList => self.handle_game_list_key_events(key_event, game),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn handle_mouse_events(&self, mouse_event: MouseEvent, game: &mut Game) -> AppResult<()> {
+        match game.current_screen {
+            CurrentScreen::Menu => self.handle_menu_mouse_events(mouse_event, game),
+            CurrentScreen::Game => self.handle_game_mouse_events(mouse_event, game),
             _ => Ok(()),
         }
     }
 
+    fn handle_menu_mouse_events(&self, mouse_event: MouseEvent, game: &mut Game) -> AppResult<()> {
+        if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Ok(());
+        }
+
+        let area = game.menu_area;
+        let is_inside = (area.left()..area.right()).contains(&mouse_event.column)
+            && (area.top()..area.bottom()).contains(&mouse_event.row);
+        let index = mouse_event.row.saturating_sub(area.y) as usize;
+
+        if is_inside && index < game.menu_state.items.len() {
+            game.menu_state.selected = index;
+            game.select_menu_item();
+        }
+
+        Ok(())
+    }
+
+    fn handle_game_mouse_events(&self, mouse_event: MouseEvent, game: &mut Game) -> AppResult<()> {
+        // The board only takes clicks while nothing else is waiting for input
+        if game.promotion_state.is_some()
+            || game.command_bar.is_some()
+            || game.replay.is_some()
+            || game.history_view.is_some()
+            || game.takeback_request.is_some()
+            || game.pending_paste.is_some()
+        {
+            return Ok(());
+        }
+
+        let (column, row) = (mouse_event.column, mouse_event.row);
+        let square = square_at(game.board_area, game.is_board_flipped(), column, row);
+
+        match mouse_event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(position) = square {
+                    game.press_square(position, column, row);
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => game.drag_to(column, row),
+            MouseEventKind::Up(MouseButton::Left) => game.drop_piece(square),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
     fn handle_menu_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
         match key_event.code {
             KeyCode::Char('q') => game.quit(),
             KeyCode::Up | KeyCode::Char('k') => game.menu_state.previous(),
             KeyCode::Down | KeyCode::Char('j') => game.menu_state.next(),
-            KeyCode::Enter => {
-                game.current_screen = CurrentScreen::Game;
-            }
+            KeyCode::Enter => game.select_menu_item(),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Line editing keys shared by the text prompts. Returns false for other keys.
+    fn handle_text_input_key_events(&self, key_event: KeyEvent, input: &mut TextInput) -> bool {
+        match key_event.code {
+            KeyCode::Char(c) => input.insert(c),
+            KeyCode::Backspace => input.delete_previous(),
+            KeyCode::Delete => input.delete_next(),
+            KeyCode::Left => input.move_left(),
+            KeyCode::Right => input.move_right(),
+            KeyCode::Home => input.move_home(),
+            KeyCode::End => input.move_end(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_fen_input_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        if self.handle_text_input_key_events(key_event, &mut game.fen_input) {
+            return Ok(());
+        }
+
+        match key_event.code {
+            KeyCode::Esc => game.current_screen = CurrentScreen::Menu,
+            KeyCode::Enter => game.load_fen(),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_pgn_input_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        if self.handle_text_input_key_events(key_event, &mut game.pgn_input) {
+            return Ok(());
+        }
+
+        match key_event.code {
+            KeyCode::Esc => game.current_screen = CurrentScreen::Menu,
+            KeyCode::Enter => game.load_pgn_file(),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_game_list_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        match key_event.code {
+            KeyCode::Char('q') => game.quit(),
+            KeyCode::Up | KeyCode::Char('k') => game.game_list.previous(),
+            KeyCode::Down | KeyCode::Char('j') => game.game_list.next(),
+            KeyCode::Enter => game.start_replay(),
+            KeyCode::Esc => game.current_screen = CurrentScreen::Menu,
             _ => {}
         }
 
@@ -96,17 +220,44 @@ impl MessageHandler {
     }
 
     fn handle_game_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        if game.promotion_state.is_some() {
+            return self.handle_promotion_key_events(key_event, game);
+        }
+        if game.takeback_request.is_some() {
+            return self.handle_takeback_key_events(key_event, game);
+        }
+        if game.pending_paste.is_some() {
+            return self.handle_paste_key_events(key_event, game);
+        }
+        if game.command_bar.is_some() {
+            return self.handle_command_bar_key_events(key_event, game);
+        }
+        if game.replay.is_some() {
+            return self.handle_replay_key_events(key_event, game);
+        }
+        if game.history_view.is_some() {
+            return self.handle_history_key_events(key_event, game);
+        }
+
         match key_event.code {
             KeyCode::Char('q') => game.quit(),
-            KeyCode::Up | KeyCode::Char('k') => game.board.move_cursor(Direction::Up),
-            KeyCode::Down | KeyCode::Char('j') => game.board.move_cursor(Direction::Down),
-            KeyCode::Left | KeyCode::Char('h') => game.board.move_cursor(Direction::Left),
-            KeyCode::Right | KeyCode::Char('l') => game.board.move_cursor(Direction::Right),
+            KeyCode::Char('w') => game.save_pgn(),
+            KeyCode::Char(':') => game.open_command_bar(),
+            KeyCode::Char('f') => game.flip_board(),
+            KeyCode::Char('a') => game.toggle_auto_orientation(),
+            KeyCode::Char('[') => game.history_back(),
+            KeyCode::Char('u') => game.undo_move(),
+            KeyCode::Char('r') => game.redo_move(),
+            KeyCode::Home => game.history_to_start(),
+            KeyCode::Up | KeyCode::Char('k') => game.move_board_cursor(Direction::Up),
+            KeyCode::Down | KeyCode::Char('j') => game.move_board_cursor(Direction::Down),
+            KeyCode::Left | KeyCode::Char('h') => game.move_board_cursor(Direction::Left),
+            KeyCode::Right | KeyCode::Char('l') => game.move_board_cursor(Direction::Right),
             KeyCode::Enter => {
                 let cursor_position = game.board.cursor_position.clone();
                 if let Some(legal_moves) = &game.board.currently_legal_moves {
                     if legal_moves.contains(&cursor_position) {
-                        game.board.move_piece(&cursor_position);
+                        game.move_to_cursor();
                     } else {
                         game.board.select_position(&cursor_position);
                     }
@@ -118,4 +269,96 @@ impl MessageHandler {
         };
         Ok(())
     }
+
+    fn handle_command_bar_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        let Some(command_bar) = &mut game.command_bar else {
+            return Ok(());
+        };
+
+        if self.handle_text_input_key_events(key_event, &mut command_bar.input) {
+            command_bar.update(&game.board);
+            return Ok(());
+        }
+
+        match key_event.code {
+            KeyCode::Tab => command_bar.complete(&game.board),
+            KeyCode::Enter => game.submit_command(),
+            KeyCode::Esc => game.close_command_bar(),
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn handle_replay_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        let Some(replay) = &mut game.replay else {
+            return Ok(());
+        };
+
+        match key_event.code {
+            KeyCode::Char('q') => game.quit(),
+            KeyCode::Left | KeyCode::Char('h') => {
+                replay.back(&mut game.board);
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                replay.forward(&mut game.board);
+            }
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Home => replay.to_start(&mut game.board),
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::End => replay.to_end(&mut game.board),
+            KeyCode::Char('f') => game.flip_board(),
+            KeyCode::Char('a') => game.toggle_auto_orientation(),
+            KeyCode::Esc => game.leave_replay(),
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn handle_history_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        match key_event.code {
+            KeyCode::Char('q') => game.quit(),
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('[') => game.history_back(),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(']') => game.history_forward(),
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Home => game.history_to_start(),
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::End | KeyCode::Esc => {
+                game.return_to_game()
+            }
+            KeyCode::Char('f') => game.flip_board(),
+            KeyCode::Char('a') => game.toggle_auto_orientation(),
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn handle_takeback_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        match key_event.code {
+            KeyCode::Char('q') => game.quit(),
+            KeyCode::Char('y') => game.answer_takeback(true),
+            KeyCode::Char('n') | KeyCode::Esc => game.answer_takeback(false),
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn handle_paste_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        match key_event.code {
+            KeyCode::Char('q') => game.quit(),
+            KeyCode::Char('y') => game.answer_paste(true),
+            KeyCode::Char('n') | KeyCode::Esc => game.answer_paste(false),
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn handle_promotion_key_events(&self, key_event: KeyEvent, game: &mut Game) -> AppResult<()> {
+        match key_event.code {
+            KeyCode::Char('q') => game.quit(),
+            KeyCode::Up | KeyCode::Char('k') => game.move_promotion_cursor(Direction::Up),
+            KeyCode::Down | KeyCode::Char('j') => game.move_promotion_cursor(Direction::Down),
+            KeyCode::Left | KeyCode::Char('h') => game.move_promotion_cursor(Direction::Left),
+            KeyCode::Right | KeyCode::Char('l') => game.move_promotion_cursor(Direction::Right),
+            KeyCode::Enter => game.confirm_promotion(),
+            KeyCode::Esc => game.cancel_promotion(),
+            _ => {}
+        };
+        Ok(())
+    }
 }
@@ -1,14 +1,23 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style, Stylize},
-    widgets::{Block, Paragraph, StatefulWidget, Widget, Wrap},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    widgets::{Block, Paragraph, StatefulWidget, Widget},
     Frame,
 };
 
-use crate::{game::Game, model::Board};
+use crate::{
+    game::Game,
+    model::{orientation::screen_position, Board, PieceColor, Position},
+};
 
-use super::constants::{BLACK, WHITE};
+use super::{
+    captures::render_captures,
+    clock::render_clock,
+    command_bar::render_command_bar,
+    constants::{BLACK, WHITE},
+    move_list::render_move_list,
+};
 
 pub fn render_game(frame: &mut Frame, main_area: Rect, game: &mut Game) {
     let main_layout_horizontal = Layout::default()
@@ -36,72 +45,265 @@ pub fn render_game(frame: &mut Frame, main_area: Rect, game: &mut Game) {
         )
         .split(main_layout_horizontal[1]);
 
-    frame.render_stateful_widget(game.board.clone(), main_layout_vertical[1], &mut game.board);
-    frame.render_stateful_widget(Debugger, main_layout_vertical[3],  &mut game.board);
+    game.board_area = main_layout_vertical[1];
+    let flipped = game.is_board_flipped();
+    let header = match (&game.replay, &game.history_view) {
+        (Some(replay), _) => Some(format!(
+            "{}  move {}/{}  (h/l: step, k/j: start/end, Esc: game list)",
+            replay.title,
+            replay.ply,
+            replay.moves.len()
+        )),
+        (None, Some(history_view)) => Some(format!(
+            "Viewing move {}/{}  (h/l: step, k: start, Esc: back to game)",
+            history_view.ply,
+            game.move_list.sans.len()
+        )),
+        (None, None) => game.status_message.clone(),
+    };
+
+    let board = match &mut game.history_view {
+        Some(history_view) => &mut history_view.board,
+        None => &mut game.board,
+    };
+    frame.render_stateful_widget(BoardView { flipped }, main_layout_vertical[1], board);
+
+    // Each side's captures sit next to its own back rank
+    let squares = square_areas(game.board_area, flipped);
+    let (top_row, bottom_row) = if flipped { (7, 0) } else { (0, 7) };
+    let (top_color, bottom_color) = if flipped {
+        (PieceColor::White, PieceColor::Black)
+    } else {
+        (PieceColor::Black, PieceColor::White)
+    };
+    let side_area = main_layout_vertical[0];
+    let mut top_area = Rect {
+        y: squares[top_row][0].y.max(side_area.y),
+        height: side_area.bottom().saturating_sub(squares[top_row][0].y),
+        ..side_area
+    };
+    let mut bottom_area = Rect {
+        height: squares[bottom_row][0].bottom().saturating_sub(side_area.y),
+        ..side_area
+    };
+    if let (Some(clock), None) = (&game.clock, &game.replay) {
+        // Clocks go on the outside, captures between them and the board's middle
+        if top_area.height > 0 && bottom_area.height > 0 {
+            let top_clock = Rect {
+                height: 1,
+                ..top_area
+            };
+            let bottom_clock = Rect {
+                y: bottom_area.bottom() - 1,
+                height: 1,
+                ..bottom_area
+            };
+            render_clock(frame, top_clock, clock, top_color);
+            render_clock(frame, bottom_clock, clock, bottom_color);
+            top_area.y += 1;
+            top_area.height -= 1;
+            bottom_area.height -= 1;
+        }
+    }
+    render_captures(frame, top_area, game.displayed_board(), top_color, false);
+    render_captures(
+        frame,
+        bottom_area,
+        game.displayed_board(),
+        bottom_color,
+        true,
+    );
+
+    if let Some(drag) = &game.drag {
+        // Empty the origin square and draw the piece centered on the pointer
+        let origin = squares[drag.from.x][drag.from.y];
+        frame.render_widget(Block::default().bg(Color::Cyan), origin);
+
+        let board_area = game.board_area;
+        let ghost = Rect {
+            x: drag.column.saturating_sub(origin.width / 2).clamp(
+                board_area.left(),
+                board_area.right().saturating_sub(origin.width),
+            ),
+            y: drag.row.saturating_sub(origin.height / 2).clamp(
+                board_area.top(),
+                board_area.bottom().saturating_sub(origin.height),
+            ),
+            width: origin.width,
+            height: origin.height,
+        };
+        frame.render_widget(drag.piece, ghost);
+    }
+    let (move_list, ply) = game.move_list();
+    render_move_list(frame, main_layout_vertical[3], move_list, ply);
+    match &game.command_bar {
+        Some(command_bar) => render_command_bar(frame, main_layout_horizontal[2], command_bar),
+        None => {
+            let board = match &mut game.history_view {
+                Some(history_view) => &mut history_view.board,
+                None => &mut game.board,
+            };
+            frame.render_stateful_widget(StatusBar, main_layout_horizontal[2], board)
+        }
+    }
+
+    if let Some(header) = header {
+        frame.render_widget(
+            Paragraph::new(header)
+                .alignment(Alignment::Center)
+                .style(Style::new().fg(Color::Gray)),
+            main_layout_horizontal[0],
+        );
+    }
+
+    if let Some(promotion_state) = &mut game.promotion_state {
+        frame.render_stateful_widget(
+            promotion_state.clone(),
+            main_layout_vertical[1],
+            promotion_state,
+        );
+    }
 }
 
-pub struct Debugger;
+pub struct StatusBar;
 
-impl StatefulWidget for Debugger {
+impl StatefulWidget for StatusBar {
     type State = Board;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let block = Block::default().title("Debugger").bg(Color::Black).fg(Color::White);
+        let (status, color) = if state.result.is_over() {
+            (state.result.to_string(), Color::Yellow)
+        } else if state.is_check {
+            (format!("{:?} to move - Check!", state.on_turn), Color::Red)
+        } else {
+            (format!("{:?} to move", state.on_turn), Color::White)
+        };
 
-        let paragraph = Paragraph::new(state.to_string())
-            .block(block)
-            .wrap( Wrap { trim: true})
-            .style(Style::new().fg(Color::White).bg(Color::Black));
+        let paragraph = Paragraph::new(status)
+            .alignment(Alignment::Center)
+            .style(Style::new().fg(color).add_modifier(Modifier::BOLD));
 
         Widget::render(paragraph, area, buf);
     }
 }
 
-impl StatefulWidget for Board {
+/// Screen areas of the 64 squares, indexed like `Board::board`.
+pub fn square_areas(area: Rect, flipped: bool) -> [[Rect; 8]; 8] {
+    // Keep at least one column on each side for the rank labels
+    let cell_side_length = area.width.saturating_sub(2) / 8;
+    let border_length = area.width / 2 - (4 * cell_side_length);
+    let constraints = [
+        Constraint::Length(border_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(cell_side_length),
+        Constraint::Length(border_length),
+    ];
+
+    let columns = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints.as_ref())
+        .split(area);
+
+    let mut squares = [[Rect::default(); 8]; 8];
+    squares.iter_mut().enumerate().for_each(|(i, row)| {
+        let lines = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints.as_ref())
+            .split(columns[i + 1]);
+        row.iter_mut()
+            .enumerate()
+            .for_each(|(j, square)| *square = lines[j + 1]);
+    });
+
+    let mut board_squares = [[Rect::default(); 8]; 8];
+    board_squares.iter_mut().enumerate().for_each(|(x, row)| {
+        row.iter_mut().enumerate().for_each(|(y, square)| {
+            let screen = screen_position(&Position { x, y }, flipped);
+            *square = squares[screen.x][screen.y];
+        })
+    });
+
+    board_squares
+}
+
+/// Square under the terminal cell at `column`, `row`, if any.
+pub fn square_at(area: Rect, flipped: bool, column: u16, row: u16) -> Option<Position> {
+    square_areas(area, flipped)
+        .iter()
+        .enumerate()
+        .find_map(|(x, line)| {
+            line.iter()
+                .position(|square| {
+                    (square.left()..square.right()).contains(&column)
+                        && (square.top()..square.bottom()).contains(&row)
+                })
+                .map(|y| Position { x, y })
+        })
+}
+
+fn file_label(y: usize) -> char {
+    (b'a' + y as u8) as char
+}
+
+fn rank_label(x: usize) -> char {
+    (b'8' - x as u8) as char
+}
+
+/// Rank numbers left of the board and file letters below it, in the border areas.
+fn render_coordinates(area: Rect, squares: &[[Rect; 8]; 8], flipped: bool, buf: &mut Buffer) {
+    let style = Style::new().fg(Color::Gray);
+
+    squares.iter().enumerate().for_each(|(x, row)| {
+        let square = row[if flipped { 7 } else { 0 }];
+        let label_area = Rect {
+            x: area.x,
+            y: square.y + square.height / 2,
+            width: square.x.saturating_sub(area.x),
+            height: 1.min(square.height),
+        };
+        Paragraph::new(format!("{} ", rank_label(x)))
+            .alignment(Alignment::Right)
+            .style(style)
+            .render(label_area, buf);
+    });
+
+    squares[if flipped { 0 } else { 7 }]
+        .iter()
+        .enumerate()
+        .for_each(|(y, square)| {
+            if square.bottom() < area.bottom() {
+                let label_area = Rect {
+                    y: square.bottom(),
+                    height: 1,
+                    ..*square
+                };
+                Paragraph::new(file_label(y).to_string())
+                    .alignment(Alignment::Center)
+                    .style(style)
+                    .render(label_area, buf);
+            }
+        });
+}
+
+/// The board as seen from White, or from Black when `flipped`.
+pub struct BoardView {
+    pub flipped: bool,
+}
+
+impl StatefulWidget for BoardView {
     type State = Board;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let cell_side_length = area.width / 8;
-        let border_length = area.width / 2 - (4 * cell_side_length);
-
-        let columns = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(
-                [
-                    Constraint::Length(border_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(cell_side_length),
-                    Constraint::Length(border_length),
-                ]
-                .as_ref(),
-            )
-            .split(area);
+        let squares = square_areas(area, self.flipped);
+        render_coordinates(area, &squares, self.flipped, buf);
 
         state.board.iter().enumerate().for_each(|(i, row)| {
-            let lines = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(
-                    [
-                        Constraint::Length(border_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(cell_side_length),
-                        Constraint::Length(border_length),
-                    ]
-                    .as_ref(),
-                )
-                .split(columns[i + 1]);
             row.iter().enumerate().for_each(|(j, c)| {
                 let mut cell_color: Color = if (i + j) % 2 == 0 { WHITE } else { BLACK };
 
@@ -124,7 +326,7 @@ impl StatefulWidget for Board {
                 }
 
                 let cell = Block::default().bg(cell_color);
-                let square = lines[j + 1];
+                let square = squares[i][j];
                 Widget::render(cell, square, buf);
                 if let Some(piece) = c {
                     piece.render(square, buf);
@@ -0,0 +1,58 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::model::{Board, PieceColor};
+
+/// Pieces taken by `color`, one line per type, and its material lead if it has one.
+/// With `at_bottom` the lines end at the bottom of `area` instead of starting at its top.
+pub fn render_captures(
+    frame: &mut Frame,
+    area: Rect,
+    board: &Board,
+    color: PieceColor,
+    at_bottom: bool,
+) {
+    let mut lines: Vec<Line> = board
+        .captures_by(color)
+        .iter()
+        .map(|(piece, count)| {
+            Line::styled(
+                piece.unicode_symbol().to_string().repeat(*count),
+                Style::default().fg(Color::Gray),
+            )
+        })
+        .collect();
+
+    let advantage = board.material_advantage(color);
+    if advantage > 0 {
+        lines.push(Line::styled(
+            format!("+{}", advantage),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let height = (lines.len() as u16).min(area.height);
+    let lines_area = Rect {
+        y: if at_bottom {
+            area.bottom() - height
+        } else {
+            area.y
+        },
+        height,
+        // Leave a gap before the rank labels
+        width: area.width.saturating_sub(1),
+        ..area
+    };
+
+    frame.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Right),
+        lines_area,
+    );
+}
@@ -0,0 +1,43 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::model::{
+    clock::{format_clock, ChessClock},
+    PieceColor,
+};
+
+/// Time left for `color`: highlighted while running, red when low or out of time.
+pub fn render_clock(frame: &mut Frame, area: Rect, clock: &ChessClock, color: PieceColor) {
+    let player = clock.player(color);
+    let running = clock.running == Some(color);
+
+    let style = if clock.flagged == Some(color) || (running && clock.is_low(color)) {
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD)
+    } else if running {
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::White)
+            .add_modifier(Modifier::BOLD)
+    } else if clock.is_low(color) {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            format!(" {} ", format_clock(player.remaining)),
+            style,
+        )))
+        .alignment(Alignment::Right),
+        area,
+    );
+}
@@ -0,0 +1,64 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::model::command_bar::CommandBarState;
+
+const PROMPT: &str = "Move: ";
+
+pub fn render_command_bar(frame: &mut Frame, area: Rect, state: &CommandBarState) {
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(area);
+
+    let input_line = Line::from(vec![
+        Span::styled(PROMPT, Style::default().fg(Color::Gray)),
+        Span::styled(
+            state.input.value.as_str(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(input_line), layout[0]);
+    frame.set_cursor(
+        layout[0].x + (PROMPT.len() + state.input.cursor) as u16,
+        layout[0].y,
+    );
+
+    // While cycling with Tab the candidates are more useful than the validation
+    let feedback = match (state.completion_index, &state.validation) {
+        (Some(selected), _) => Line::from(
+            state
+                .completions
+                .iter()
+                .enumerate()
+                .map(|(i, completion)| {
+                    let style = if i == selected {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default().fg(Color::Gray)
+                    };
+                    Span::styled(format!("{} ", completion), style)
+                })
+                .collect::<Vec<Span>>(),
+        ),
+        (None, Some(Ok(san))) => Line::styled(
+            format!("Enter to play {}", san),
+            Style::default().fg(Color::Green),
+        ),
+        (None, Some(Err(error))) => {
+            Line::styled(error.to_string(), Style::default().fg(Color::Red))
+        }
+        (None, None) => Line::styled(
+            "SAN or coordinates, Tab completes, Esc cancels",
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+    frame.render_widget(Paragraph::new(feedback), layout[1]);
+}
@@ -0,0 +1,83 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::game::Game;
+
+pub fn render_game_list(frame: &mut Frame, main_area: Rect, game: &mut Game) {
+    let horizontal_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70)])
+        .flex(Flex::Center)
+        .split(main_area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(3),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .split(horizontal_layout[0]);
+
+    let items: Vec<ListItem> = game
+        .game_list
+        .games
+        .iter()
+        .enumerate()
+        .map(|(i, pgn_game)| {
+            let details: Vec<&str> = ["Event", "Date"]
+                .iter()
+                .filter_map(|name| pgn_game.tag(name))
+                .filter(|value| !value.starts_with('?'))
+                .collect();
+
+            ListItem::new(format!(
+                "{:>3}. {}  {} moves  {}",
+                i + 1,
+                pgn_game.title(),
+                pgn_game.moves.len().div_ceil(2),
+                details.join(", ")
+            ))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Games")
+                .title_alignment(Alignment::Center),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    let mut list_state = ListState::default().with_selected(Some(game.game_list.selected));
+    frame.render_stateful_widget(list, layout[0], &mut list_state);
+
+    frame.render_widget(
+        Paragraph::new(game.pgn_error.clone().unwrap_or_default())
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(Color::Red)),
+        layout[1],
+    );
+
+    frame.render_widget(
+        Paragraph::new("Enter: replay game   Esc: back to menu")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray)),
+        layout[2],
+    );
+}
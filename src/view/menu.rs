@@ -19,6 +19,8 @@ pub fn render_menu(frame: &mut Frame, main_area: Rect, game: &mut Game) {
 
     frame.render_widget(AppTitle, layout[0]);
 
+    game.menu_area = menu_items_area(layout[1]);
+
     frame.render_stateful_widget(game.menu_state.clone(), layout[1], &mut game.menu_state);
 }
 
@@ -48,15 +50,31 @@ impl Widget for AppTitle {
     }
 }
 
+fn menu_block() -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .padding(Padding::top(2))
+        .title("Options")
+        .title_alignment(Alignment::Center)
+}
+
+fn menu_list_area(area: Rect) -> Rect {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50)])
+        .flex(Flex::Center)
+        .split(area)[0]
+}
+
+/// Area of the menu entries, one row each, for mouse hit testing.
+pub fn menu_items_area(area: Rect) -> Rect {
+    menu_block().inner(menu_list_area(area))
+}
+
 impl StatefulWidget for MenuState {
     type State = Self;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50)])
-            .flex(Flex::Center)
-            .split(area);
         let list_items: Vec<ListItem> = state
             .items
             .iter()
@@ -76,15 +94,9 @@ impl StatefulWidget for MenuState {
             })
             .collect();
         let list = List::new(list_items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .padding(Padding::top(2))
-                    .title("Options")
-                    .title_alignment(Alignment::Center),
-            )
+            .block(menu_block())
             .style(Style::default().fg(Color::White));
 
-        Widget::render(list, layout[0], buf);
+        Widget::render(list, menu_list_area(area), buf);
     }
 }
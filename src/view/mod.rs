@@ -1,6 +1,13 @@
 pub mod board;
+pub mod captures;
+pub mod clock;
+pub mod command_bar;
 pub mod constants;
+pub mod game_list;
 pub mod menu;
+pub mod move_list;
 pub mod piece;
+pub mod promotion;
+pub mod prompt;
 pub mod tui;
 pub mod ui;
@@ -0,0 +1,64 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::model::move_list::MoveList;
+
+const SAN_WIDTH: usize = 8;
+
+/// Numbered moves in two columns, with the move that led to the shown position
+/// highlighted and kept in view.
+pub fn render_move_list(frame: &mut Frame, area: Rect, move_list: &MoveList, ply: usize) {
+    let current = ply.checked_sub(1);
+    let san_span = |index: Option<usize>| match index {
+        Some(index) => {
+            let style = if Some(index) == current {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Span::styled(
+                format!("{:<width$}", move_list.sans[index], width = SAN_WIDTH),
+                style,
+            )
+        }
+        None => Span::styled(
+            format!("{:<width$}", "...", width = SAN_WIDTH),
+            Style::default().fg(Color::DarkGray),
+        ),
+    };
+
+    let items: Vec<ListItem> = move_list
+        .rows
+        .iter()
+        .map(|row| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>3}. ", row.number),
+                    Style::default().fg(Color::Gray),
+                ),
+                san_span(row.white),
+                Span::raw(" "),
+                row.black
+                    .map_or(Span::raw(""), |index| san_span(Some(index))),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Moves")
+            .title_alignment(Alignment::Center),
+    );
+
+    let mut list_state = ListState::default().with_selected(move_list.row_of(ply));
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
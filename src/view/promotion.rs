@@ -0,0 +1,61 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Stylize},
+    widgets::{Block, Borders, Clear, StatefulWidget, Widget},
+};
+
+use crate::model::{
+    promotion::{PromotionState, PROMOTION_PIECES},
+    Piece,
+};
+
+use super::constants::{BLACK, WHITE};
+
+impl StatefulWidget for PromotionState {
+    type State = Self;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // Picker cells are sized like board squares so the glyphs fit
+        let cell_side_length = area.width / 8;
+        let width = (cell_side_length * 4 + 2).min(area.width);
+        let height = (cell_side_length + 2).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        Widget::render(Clear, popup, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Promote to")
+            .fg(Color::White)
+            .bg(Color::Black);
+        let inner = block.inner(popup);
+        Widget::render(block, popup, buf);
+
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(cell_side_length); 4].as_ref())
+            .split(inner);
+
+        PROMOTION_PIECES
+            .iter()
+            .enumerate()
+            .for_each(|(i, piece_type)| {
+                let cell_color = if state.selected == i {
+                    Color::Blue
+                } else if i % 2 == 0 {
+                    WHITE
+                } else {
+                    BLACK
+                };
+
+                Widget::render(Block::default().bg(cell_color), cells[i], buf);
+                Piece::new(*piece_type, state.color).render(cells[i], buf);
+            });
+    }
+}
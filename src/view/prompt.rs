@@ -0,0 +1,71 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::model::text_input::TextInput;
+
+/// Centered single line input with an error line and key hints below it.
+pub fn render_prompt(
+    frame: &mut Frame,
+    main_area: Rect,
+    title: &str,
+    input: &TextInput,
+    error: Option<String>,
+    help: &str,
+) {
+    let horizontal_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(80)])
+        .flex(Flex::Center)
+        .split(main_area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ]
+            .as_ref(),
+        )
+        .flex(Flex::Center)
+        .split(horizontal_layout[0]);
+
+    let input_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_alignment(Alignment::Center);
+    let input_area = input_block.inner(layout[0]);
+
+    // Keep the cursor visible when the text is wider than the box
+    let width = input_area.width.max(1) as usize;
+    let scroll = (input.cursor + 1).saturating_sub(width);
+    let visible: String = input.value.chars().skip(scroll).collect();
+
+    frame.render_widget(
+        Paragraph::new(visible)
+            .block(input_block)
+            .style(Style::default().fg(Color::White)),
+        layout[0],
+    );
+    frame.set_cursor(input_area.x + (input.cursor - scroll) as u16, input_area.y);
+
+    frame.render_widget(
+        Paragraph::new(error.unwrap_or_default())
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(Color::Red)),
+        layout[1],
+    );
+
+    frame.render_widget(
+        Paragraph::new(help)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray)),
+        layout[2],
+    );
+}
@@ -2,9 +2,13 @@ use ratatui::{
     backend::Backend,
     crossterm::{
         self,
-        event::{DisableMouseCapture, EnableMouseCapture},
+        event::{
+            DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+            EnableFocusChange, EnableMouseCapture,
+        },
         terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
     },
+    layout::Rect,
     Terminal,
 };
 use std::{
@@ -35,7 +39,13 @@ impl<B: Backend> Tui<B> {
 
     pub fn init(&mut self) -> AppResult<()> {
         terminal::enable_raw_mode()?;
-        crossterm::execute!(io::stderr(), EnterAlternateScreen, EnableMouseCapture)?;
+        crossterm::execute!(
+            io::stderr(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste,
+            EnableFocusChange
+        )?;
 
         let panic_hook = take_hook();
         set_hook(Box::new(move |panic| {
@@ -53,6 +63,12 @@ impl<B: Backend> Tui<B> {
         Ok(())
     }
 
+    /// Resizes the buffers right away, so the next draw lays out for the new size.
+    pub fn resize(&mut self, width: u16, height: u16) -> AppResult<()> {
+        self.terminal.resize(Rect::new(0, 0, width, height))?;
+        Ok(())
+    }
+
     pub fn exit(&mut self) -> AppResult<()> {
         Self::reset()?;
         self.terminal.show_cursor()?;
@@ -61,7 +77,13 @@ impl<B: Backend> Tui<B> {
 
     fn reset() -> AppResult<()> {
         terminal::disable_raw_mode()?;
-        crossterm::execute!(io::stderr(), LeaveAlternateScreen, DisableMouseCapture)?;
+        crossterm::execute!(
+            io::stderr(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableBracketedPaste,
+            DisableFocusChange
+        )?;
         Ok(())
     }
 }
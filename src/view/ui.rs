@@ -2,7 +2,9 @@ use ratatui::Frame;
 
 use crate::game::{CurrentScreen, Game};
 
-use super::{board::render_game, menu::render_menu};
+use super::{
+    board::render_game, game_list::render_game_list, menu::render_menu, prompt::render_prompt,
+};
 
 pub fn render(game: &mut Game, frame: &mut Frame) {
     let main_area = frame.size();
@@ -10,6 +12,25 @@ pub fn render(game: &mut Game, frame: &mut Frame) {
     match game.current_screen {
         CurrentScreen::Menu => render_menu(frame, main_area, game),
         CurrentScreen::Game => render_game(frame, main_area, game),
+        CurrentScreen::FenInput => render_prompt(
+            frame,
+            main_area,
+            "Paste or type a FEN",
+            &game.fen_input,
+            game.fen_error
+                .as_ref()
+                .map(|error| format!("Invalid FEN: {}", error)),
+            "Enter: start game   Esc: back to menu",
+        ),
+        CurrentScreen::PgnInput => render_prompt(
+            frame,
+            main_area,
+            "Path to a PGN file",
+            &game.pgn_input,
+            game.pgn_error.clone(),
+            "Enter: open file   Esc: back to menu",
+        ),
+        CurrentScreen::GameList => render_game_list(frame, main_area, game),
         CurrentScreen::Exit => {}
     }
 }